@@ -35,7 +35,27 @@
 // - compact representation
 // - readable ints
 
-use std::{cmp, fmt, ops};
+// clippy's suggested replacements for these lints need a much newer toolchain than the rest of
+// the crate
+#![allow(clippy::manual_is_multiple_of, clippy::manual_div_ceil)]
+// without the default `std` feature only `core` and `alloc` are used; the tests always have `std`
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{cmp, convert::TryFrom, fmt, iter, ops, str};
+#[cfg(feature = "std")]
+use std::{error, io};
+
+use nybble::{
+    decimal_add_with_carry, decimal_digit_high, decimal_digit_low, decimal_digit_nybble,
+    decimal_digits, decimal_mul_with_carry, decimal_subtract_with_carry,
+};
+
+// the shorter operand's length, in decimal digits, from which multiplication switches from the
+// schoolbook method to Karatsuba's; in release builds the two cross over at around 100-200 digits
+const KARATSUBA_THRESHOLD: usize = 96;
 
 /// A semi-infinite-precision integer type.
 ///
@@ -72,6 +92,7 @@ use std::{cmp, fmt, ops};
 /// ```lang-none
 /// 137 = [0111_0011, 0001_0000] = [(7, 3), (1, 0)]
 /// ```
+#[derive(Clone)]
 pub struct Infinint {
     negative: bool,
     digits_vec: Vec<u8>,
@@ -79,6 +100,16 @@ pub struct Infinint {
 
 #[allow(dead_code)]
 impl Infinint {
+    /// The maximum number of decimal digits a result may have before the `checked_*` methods
+    /// return `None`. Infinint never overflows, so this instead guards against runaway allocation.
+    pub const MAX_CHECKED_DIGITS: usize = 1_000_000;
+
+    /// The version byte written by [`encode()`](#method.encode).
+    pub const ENCODING_VERSION: u8 = 1;
+
+    // a version byte, a sign byte and a LEB128 varint of up to 10 bytes
+    const MAX_HEADER_LEN: usize = 12;
+
     /// Initializes a new Infinint with the value +0.
     ///
     /// # Examples
@@ -93,8 +124,207 @@ impl Infinint {
         }
     }
 
+    /// Initializes a new Infinint with the value +0, as the starting point for a value which
+    /// should never go negative. This is the same as [`new()`](#method.new), but says so at the
+    /// call site; there is no separate unsigned type, so use
+    /// [`as_unsigned()`](#method.as_unsigned) or [`try_into_unsigned()`](#method.try_into_unsigned)
+    /// to check that a value is still non-negative before handing it on.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut total = Infinint::new_unsigned();
+    /// total += &Infinint::from(1998);
+    /// assert!(total.as_unsigned().is_some());
+    /// ```
+    pub fn new_unsigned() -> Infinint {
+        Infinint::new()
+    }
+
+    /// Returns an Infinint with the value 0, the same as [`new()`](#method.new).
+    ///
+    /// The digits live in a `Vec`, so there can be no `const` zero; this is the closest
+    /// equivalent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::zero(), Infinint::from(0));
+    /// ```
+    #[inline]
+    pub fn zero() -> Infinint {
+        Infinint::new()
+    }
+
+    /// Returns an Infinint with the value 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::one(), Infinint::from(1));
+    /// ```
+    #[inline]
+    pub fn one() -> Infinint {
+        Infinint {
+            negative: false,
+            digits_vec: vec![0x10],
+        }
+    }
+
+    /// Returns an Infinint with the value 10^`n`, built directly from its packed digits rather
+    /// than by repeated multiplication.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::pow10(3), Infinint::from(1000));
+    /// assert_eq!(Infinint::pow10(20), Infinint::from(10).pow(20));
+    /// ```
+    pub fn pow10(n: usize) -> Infinint {
+        Infinint {
+            negative: false,
+            digits_vec: Infinint::pow10_digits_vec(n),
+        }
+    }
+
+    /// Initializes a new Infinint with the value +0, with room for at least `decimal_digits`
+    /// digits before it needs to reallocate.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::with_capacity(1000);
+    /// assert!(x.is_zero());
+    /// ```
+    pub fn with_capacity(decimal_digits: usize) -> Infinint {
+        // two digits are packed into each byte
+        let mut digits_vec = Vec::with_capacity(cmp::max((decimal_digits + 1) / 2, 1));
+        digits_vec.push(0);
+        Infinint {
+            negative: false,
+            digits_vec,
+        }
+    }
+
+    /// Reserves room for at least `additional_digits` more decimal digits than the Infinint
+    /// currently has.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut x = Infinint::from(1998);
+    /// x.reserve(1000);
+    /// assert_eq!(x, Infinint::from(1998));
+    /// ```
+    pub fn reserve(&mut self, additional_digits: usize) {
+        self.digits_vec.reserve((additional_digits + 1) / 2);
+    }
+
+    /// Returns the Infinint in canonical form, with any most-significant zero bytes trimmed and a
+    /// negative zero made positive. Values produced by the arithmetic operators are already
+    /// canonical.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998).normalized();
+    /// assert_eq!(x, Infinint::from(1998));
+    /// ```
+    pub fn normalized(mut self) -> Infinint {
+        Infinint::trim_digits_vec(&mut self.digits_vec);
+        self.negative = self.negative && !self.is_zero();
+        self
+    }
+
+    /// Returns a boolean indicating if the Infinint is in the canonical form that
+    /// [`normalized()`](#method.normalized) produces: no most-significant zero bytes, no negative
+    /// zero, and only decimal digits in every nybble. Every value the crate builds is canonical,
+    /// so this is mainly for asserting that in tests and while debugging.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(-1998).is_canonical());
+    /// assert!((&Infinint::from(1000) - &Infinint::from(999)).is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool {
+        let minimal = match self.digits_vec.split_last() {
+            Some((0, [])) => !self.negative,
+            Some((last, _)) => *last != 0,
+            None => false,
+        };
+        minimal
+            && self
+                .digits_vec
+                .iter()
+                .all(|byte| decimal_digits(*byte).is_ok())
+    }
+
+    /// Normalizes the Infinint like [`normalized`](#method.normalized), then releases any unused
+    /// capacity.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut x = Infinint::with_capacity(1000);
+    /// x.shrink_to_fit();
+    /// assert!(x.is_zero());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        Infinint::trim_digits_vec(&mut self.digits_vec);
+        self.negative = self.negative && !self.is_zero();
+        self.digits_vec.shrink_to_fit();
+    }
+
+    /// Returns the factorial of `n`, the product of all integers from 1 to `n`. The factorial of
+    /// zero is one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::factorial(10);
+    /// assert_eq!(x, Infinint::from(3_628_800));
+    /// ```
+    pub fn factorial(n: u32) -> Infinint {
+        let mut result = Infinint::from(1);
+        for i in 2..=n {
+            result *= &Infinint::from(i);
+        }
+
+        result
+    }
+
+    /// Returns the binomial coefficient C(`n`, `k`), the number of ways to choose `k` items from
+    /// `n`. This is zero when `k` is greater than `n`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::binomial(10, 5), Infinint::from(252));
+    /// assert_eq!(Infinint::binomial(3, 5), Infinint::new());
+    /// ```
+    pub fn binomial(n: u32, k: u32) -> Infinint {
+        if k > n {
+            return Infinint::new();
+        }
+        // C(n, k) = C(n, n - k), so take the shorter loop
+        let k = cmp::min(k, n - k);
+
+        // after step i the result is C(n - k + i, i), so each division is exact
+        let mut result = Infinint::from(1);
+        for i in 1..=k {
+            result *= &Infinint::from(n - k + i);
+            result = result.divmod_small(i).0;
+        }
+
+        result
+    }
+
     /// Returns a boolean indicating if the Infinint is negative.
     ///
+    /// This is the raw sign flag. A zero built by hand can carry a set flag, so prefer
+    /// [`is_negative()`](#method.is_negative) to ask whether the value is below zero.
+    ///
     /// # Examples
     /// ```rust
     /// # use infinint::Infinint;
@@ -106,9 +336,58 @@ impl Infinint {
         self.negative
     }
 
+    /// Returns a boolean indicating if the Infinint is greater than zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(3).is_positive());
+    /// assert!(!Infinint::from(0).is_positive());
+    /// assert!(!Infinint::from(-3).is_positive());
+    /// ```
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    /// Returns a boolean indicating if the Infinint is less than zero. Unlike
+    /// [`negative()`](#method.negative), this is false for zero even if the sign flag is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(-3).is_negative());
+    /// assert!(!Infinint::from(0).is_negative());
+    /// assert!(!Infinint::from(3).is_negative());
+    /// ```
+    pub fn is_negative(&self) -> bool {
+        self.negative && !self.is_zero()
+    }
+
+    /// Returns the sign of the Infinint. Zero is always `Sign::Zero`, even if it was constructed
+    /// as a negative zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, Sign};
+    /// assert_eq!(Infinint::from(-3).sign(), Sign::Negative);
+    /// assert_eq!(Infinint::from(0).sign(), Sign::Zero);
+    /// assert_eq!(Infinint::from(3).sign(), Sign::Positive);
+    /// ```
+    pub fn sign(&self) -> Sign {
+        if self.is_zero() {
+            Sign::Zero
+        } else if self.negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
+    }
+
     /// Returns a vector where each element is a single digit of the Infinint.
     ///
-    /// As with the underlying data, the digits are returned in little-endian order.
+    /// As with the underlying data, the digits are returned in little-endian order: the first
+    /// element is the ones digit. Use [`digits_be()`](#method.digits_be) for the order in which
+    /// the number is normally read.
     ///
     /// # Examples
     /// ```rust
@@ -128,491 +407,6663 @@ impl Infinint {
             digits.push(digit_pair.0);
             digits.push(digit_pair.1);
         }
-        match digits.last() {
-            Some(d) if *d == 0 => digits.pop(),
-            _ => None,
-        };
+        // remove every most-significant zero, keeping a single 0 for the value zero
+        while digits.len() > 1 && digits.last() == Some(&0) {
+            digits.pop();
+        }
 
         digits
     }
 
-    fn digits_vec_from_int(n: u128) -> Vec<u8> {
-        let mut n = n;
-
-        let bytes_needed = match n {
-            0 => 1,
-            _ => (((n as f64).abs().log10()) as usize / 2) + 1,
-        };
-        let next_exp = (bytes_needed as f64).log2().ceil();
-        let next_pow_of_two = 2_i128.pow(next_exp as u32);
-        let mut digits_vec: Vec<u8> = Vec::with_capacity(next_pow_of_two as usize);
+    /// Returns an iterator over the decimal digits of the Infinint in little-endian order, like
+    /// [`digits()`](#method.digits) but reading each digit from the packed bytes as it goes
+    /// instead of collecting them into a vector.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// assert_eq!(x.digit_iter().collect::<Vec<_>>(), [8, 9, 9, 1]);
+    /// assert_eq!(x.digit_iter().max(), Some(9));
+    /// ```
+    pub fn digit_iter(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..self.num_digits()).map(move |i| self.digit_at(i))
+    }
 
-        if n > 0 {
-            while n > 0 {
-                let n_mod = (n % 10) as u8;
-                let d = n_mod << 4;
-                n /= 10;
+    /// Returns the decimal digit at little-endian `index`, where index 0 is the ones digit, without
+    /// unpacking the other digits. Indices past the most-significant digit give 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// assert_eq!(x.digit_at(0), 8);
+    /// assert_eq!(x.digit_at(3), 1);
+    /// assert_eq!(x.digit_at(4), 0);
+    /// ```
+    pub fn digit_at(&self, index: usize) -> u8 {
+        match self.digits_vec.get(index / 2) {
+            Some(byte) if index % 2 == 0 => decimal_digit_high(*byte).unwrap(),
+            Some(byte) => decimal_digit_low(*byte).unwrap(),
+            None => 0,
+        }
+    }
 
-                let n_mod = (n % 10) as u8;
-                let d = n_mod | d;
-                n /= 10;
+    /// Returns how many of the Infinint's decimal digits are equal to `d`. Zero has a single `0`
+    /// digit.
+    ///
+    /// `d` should be between 0 and 9; this is checked in debug builds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(1999).count_digit(9), 3);
+    /// assert_eq!(Infinint::from(100).count_digit(0), 2);
+    /// ```
+    pub fn count_digit(&self, d: u8) -> usize {
+        debug_assert!(d < 10, "digit out of range: {}", d);
 
-                digits_vec.push(d);
-            }
+        let count = self
+            .digits_vec
+            .iter()
+            .map(|byte| {
+                let (high, low) = decimal_digits(*byte).unwrap();
+                usize::from(high == d) + usize::from(low == d)
+            })
+            .sum();
+        // an odd number of digits leaves a padding zero in the most-significant nybble
+        if d == 0 && self.num_digits() % 2 == 1 {
+            count - 1
         } else {
-            digits_vec.push(0);
+            count
         }
-
-        digits_vec
     }
 
-    fn cmp_digits(n_digits_vec: &Vec<u8>, m_digits_vec: &Vec<u8>) -> cmp::Ordering {
-        let mut self_iter = n_digits_vec.iter().rev();
-        let mut other_iter = m_digits_vec.iter().rev();
-
-        loop {
-            let n_next_digits = *self_iter.next().unwrap_or(&0);
-            let m_next_digits = *other_iter.next().unwrap_or(&0);
-
-            if n_next_digits == 0 && m_next_digits == 0 {
-                return cmp::Ordering::Equal;
-            }
-
-            let n_next_digits = decimal_digits(n_next_digits).unwrap();
-            let m_next_digits = decimal_digits(m_next_digits).unwrap();
+    /// Rounds the Infinint to the nearest multiple of 10^`k`, with halves rounded away from zero.
+    /// The digit at position `k - 1` decides the direction.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(1234).round_to_pow10(2), Infinint::from(1200));
+    /// assert_eq!(Infinint::from(1250).round_to_pow10(2), Infinint::from(1300));
+    /// assert_eq!(Infinint::from(-1250).round_to_pow10(2), Infinint::from(-1300));
+    /// ```
+    pub fn round_to_pow10(&self, k: usize) -> Infinint {
+        if k == 0 {
+            return self.clone();
+        }
 
-            if n_next_digits.1 < m_next_digits.1 {
-                return cmp::Ordering::Less;
-            } else if n_next_digits.1 > m_next_digits.1 {
-                return cmp::Ordering::Greater;
-            }
+        let mut digits_vec = self.digits_vec_without_low_digits(k);
+        if self.digit_at(k - 1) >= 5 {
+            Infinint::add_digits_in_place(&mut digits_vec, &Infinint::pow10_digits_vec(k));
+        }
+        Infinint::trim_digits_vec(&mut digits_vec);
+        let negative = self.negative && digits_vec != [0];
 
-            if n_next_digits.0 < m_next_digits.0 {
-                return cmp::Ordering::Less;
-            } else if n_next_digits.0 > m_next_digits.0 {
-                return cmp::Ordering::Greater;
-            }
+        Infinint {
+            negative,
+            digits_vec,
         }
     }
 
-    fn infinint_cmp(n: &Infinint, m: &Infinint, negate_n: bool, negate_m: bool) -> cmp::Ordering {
-        let n_negative = if negate_n == false {
-            n.negative
-        } else {
-            !n.negative
-        };
-        let m_negative = if negate_m == false {
-            m.negative
-        } else {
-            !m.negative
-        };
-
-        if n_negative == true && m_negative == false {
-            cmp::Ordering::Less
-        } else if n_negative == false && m_negative == true {
-            return cmp::Ordering::Greater;
-        } else {
-            if n.digits_vec.len() < m.digits_vec.len() {
-                cmp::Ordering::Less
-            } else if n.digits_vec.len() > m.digits_vec.len() {
-                cmp::Ordering::Greater
-            } else {
-                let digits_ordering = Infinint::cmp_digits(&n.digits_vec, &m.digits_vec);
+    /// Keeps the `n` most-significant decimal digits of the Infinint and replaces the rest with
+    /// zeros, which truncates toward zero. A value with no more than `n` digits is unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(123_456).truncate_significant(2), Infinint::from(120_000));
+    /// assert_eq!(Infinint::from(-987).truncate_significant(1), Infinint::from(-900));
+    /// ```
+    pub fn truncate_significant(&self, n: usize) -> Infinint {
+        let low_digits = self.num_digits().saturating_sub(n);
+        let mut digits_vec = self.digits_vec_without_low_digits(low_digits);
+        Infinint::trim_digits_vec(&mut digits_vec);
+        let negative = self.negative && digits_vec != [0];
 
-                if n_negative == true {
-                    digits_ordering.reverse()
-                } else {
-                    digits_ordering
-                }
-            }
+        Infinint {
+            negative,
+            digits_vec,
         }
     }
 
-    fn op_digits(
-        n_digits_vec: &Vec<u8>,
-        m_digits_vec: &Vec<u8>,
-        op: fn(u8, u8, u8) -> (u8, u8),
-    ) -> Vec<u8> {
-        let mut n_iter = n_digits_vec.iter();
-        let mut m_iter = m_digits_vec.iter();
-        let mut carry = 0;
-        let mut result_digits_vec: Vec<u8> =
-            Vec::with_capacity(cmp::max(n_digits_vec.capacity(), m_digits_vec.capacity()));
-
-        let mut n_next_digits = *n_iter.next().unwrap_or(&0);
-        let mut m_next_digits = *m_iter.next().unwrap_or(&0);
+    fn digits_vec_without_low_digits(&self, k: usize) -> Vec<u8> {
+        // a copy of the packed digits with the `k` least-significant set to zero
+        let mut digits_vec = self.digits_vec.clone();
+        let whole_bytes = cmp::min(k / 2, digits_vec.len());
+        for byte in digits_vec[..whole_bytes].iter_mut() {
+            *byte = 0;
+        }
+        if let (1, Some(byte)) = (k % 2, digits_vec.get_mut(whole_bytes)) {
+            *byte &= 0x0F;
+        }
+        digits_vec
+    }
 
-        while n_next_digits != 0 || m_next_digits != 0 {
-            let n_digits = decimal_digits(n_next_digits).unwrap();
-            let m_digits = decimal_digits(m_next_digits).unwrap();
+    fn pow10_digits_vec(k: usize) -> Vec<u8> {
+        // a one in nybble `k`, which is the upper nybble of byte k / 2 when k is even
+        let mut digits_vec = vec![0; k / 2 + 1];
+        digits_vec[k / 2] = if k % 2 == 0 { 0x10 } else { 0x01 };
+        digits_vec
+    }
 
-            let (upper_result_digit, new_carry) = op(n_digits.0, m_digits.0, carry);
-            carry = new_carry;
+    /// Returns the Infinint with its decimal digits in reverse order, keeping its sign. Zeros which
+    /// end up most-significant are dropped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(1230).reverse_digits(), Infinint::from(321));
+    /// assert_eq!(Infinint::from(-1998).reverse_digits(), Infinint::from(-8991));
+    /// ```
+    pub fn reverse_digits(&self) -> Infinint {
+        let mut digits = self.digits();
+        digits.reverse();
+        let digits_vec = Infinint::digits_vec_from_digits(&digits);
 
-            let (lower_result_digit, new_carry) = op(n_digits.1, m_digits.1, carry);
-            carry = new_carry;
+        Infinint {
+            negative: self.negative && !self.is_zero(),
+            digits_vec,
+        }
+    }
 
-            let result_digit = (upper_result_digit << 4) | lower_result_digit;
-            result_digits_vec.push(result_digit);
+    /// Returns true if the Infinint's decimal digits read the same in both directions, ignoring
+    /// sign. That is, if it equals its [`reverse_digits()`](#method.reverse_digits), in magnitude.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(12321).is_palindrome());
+    /// assert!(!Infinint::from(12345).is_palindrome());
+    /// ```
+    pub fn is_palindrome(&self) -> bool {
+        let num_digits = self.num_digits();
+        (0..num_digits / 2).all(|i| self.digit_at(i) == self.digit_at(num_digits - 1 - i))
+    }
 
-            n_next_digits = *n_iter.next().unwrap_or(&0);
-            m_next_digits = *m_iter.next().unwrap_or(&0);
+    /// Returns the last `k` decimal digits of the Infinint's magnitude, which is `|self| mod 10^k`.
+    /// The result is never negative. This copies the packed digits rather than dividing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(123_456).mod_pow10(3), Infinint::from(456));
+    /// assert_eq!(Infinint::from(-123_456).mod_pow10(3), Infinint::from(456));
+    /// assert_eq!(Infinint::from(5).mod_pow10(3), Infinint::from(5));
+    /// ```
+    pub fn mod_pow10(&self, k: usize) -> Infinint {
+        let whole_bytes = cmp::min(k / 2, self.digits_vec.len());
+        let mut digits_vec = self.digits_vec[..whole_bytes].to_vec();
+        // an odd `k` also keeps the less-significant digit of the next byte
+        if let (1, Some(byte)) = (k % 2, self.digits_vec.get(whole_bytes)) {
+            digits_vec.push(byte & 0xF0);
         }
+        Infinint::trim_digits_vec(&mut digits_vec);
 
-        // possible because:
-        // - this does not apply to subtraction; we can guarantee carry == 0 at the end
-        // - if the carry is not 0 after the last run of addition, this means there is overflow
-        //     to a new digit; if there wasn't overflow, the second update of result_digit
-        //     would capture the carry
-        if carry > 0 {
-            result_digits_vec.push(carry << 4);
+        Infinint {
+            negative: false,
+            digits_vec,
         }
+    }
 
-        if result_digits_vec.len() == 0 {
-            result_digits_vec.push(0);
+    /// Adds `other` to the Infinint and wraps the sum into a counter of `digits` decimal digits,
+    /// like an odometer: the result is the sum modulo 10^`digits`, between 0 and 10^`digits` - 1.
+    /// A negative sum wraps downward from 10^`digits`, so unlike
+    /// [`mod_pow10()`](#method.mod_pow10) it does not just drop the sign.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let odometer = Infinint::from(999_999);
+    /// assert_eq!(odometer.wrapping_add_mod_pow10(&Infinint::from(1), 6), Infinint::new());
+    /// assert_eq!(Infinint::new().wrapping_add_mod_pow10(&Infinint::from(-1), 6), odometer);
+    /// ```
+    pub fn wrapping_add_mod_pow10(&self, other: &Infinint, digits: usize) -> Infinint {
+        let sum = self + other;
+        let wrapped = sum.mod_pow10(digits);
+        if sum.negative && !wrapped.is_zero() {
+            &Infinint::pow10(digits) - &wrapped
+        } else {
+            wrapped
         }
+    }
 
-        result_digits_vec
+    /// Returns the absolute value of the Infinint.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1998);
+    /// assert_eq!(x.abs(), Infinint::from(1998));
+    /// ```
+    pub fn abs(&self) -> Infinint {
+        let mut result = self.clone();
+        result.negative = false;
+        result
     }
 
-    fn infinint_add(
-        n: &Infinint,
-        m: &Infinint,
-        negate_n: bool,
-        negate_m: bool,
-        negate_result: bool,
-    ) -> Infinint {
-        let n_negative = if negate_n == false {
-            n.negative
+    /// Returns the Infinint in `Ok` if it is not negative, and gives it back in `Err` if it is, so
+    /// code working with natural numbers can reject negatives without panicking.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(5).try_into_unsigned(), Ok(Infinint::from(5)));
+    /// assert_eq!(Infinint::from(-5).try_into_unsigned(), Err(Infinint::from(-5)));
+    /// ```
+    pub fn try_into_unsigned(self) -> Result<Infinint, Infinint> {
+        if self.is_negative() {
+            Err(self)
         } else {
-            !n.negative
-        };
-        let m_negative = if negate_m == false {
-            m.negative
+            Ok(self)
+        }
+    }
+
+    /// Returns the Infinint if it is not negative, or `None` if it is. This is the borrowing
+    /// counterpart of [`try_into_unsigned()`](#method.try_into_unsigned). Zero is accepted even
+    /// if its sign flag is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(5);
+    /// assert_eq!(x.as_unsigned(), Some(&x));
+    /// assert_eq!(Infinint::from(-5).as_unsigned(), None);
+    /// ```
+    pub fn as_unsigned(&self) -> Option<&Infinint> {
+        if self.is_negative() {
+            None
         } else {
-            !m.negative
+            Some(self)
+        }
+    }
+
+    /// Returns the number of decimal digits in the Infinint, ignoring sign. Zero has one digit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1998);
+    /// assert_eq!(x.num_digits(), 4);
+    /// ```
+    pub fn num_digits(&self) -> usize {
+        match self.digits_vec.iter().rposition(|d| *d != 0) {
+            Some(i) if decimal_digit_low(self.digits_vec[i]).unwrap() == 0 => i * 2 + 1,
+            Some(i) => i * 2 + 2,
+            None => 1,
+        }
+    }
+
+    /// Returns the number of bits needed to represent the magnitude of the Infinint in binary. Zero
+    /// needs no bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(-255).bit_length(), 8);
+    /// assert_eq!(Infinint::from(256).bit_length(), 9);
+    /// ```
+    pub fn bit_length(&self) -> usize {
+        // repeatedly divide the magnitude by 2^32, most-significant digit first, until what is left
+        // fits in a u64
+        let mut digits = self.digits_be();
+        let mut bits = 0;
+
+        while digits.len() > 19 {
+            let mut remainder: u64 = 0;
+            for digit in digits.iter_mut() {
+                let acc = remainder * 10 + u64::from(*digit);
+                *digit = (acc >> 32) as u8;
+                remainder = acc & 0xFFFF_FFFF;
+            }
+            let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+            digits.drain(..leading_zeros);
+            bits += 32;
+        }
+
+        let rest = digits.iter().fold(0, |acc, d| acc * 10 + u64::from(*d));
+        bits + (64 - rest.leading_zeros()) as usize
+    }
+
+    /// Returns the number of consecutive zero decimal digits at the least-significant end of the
+    /// Infinint. By convention zero has no trailing zeros, matching the fact that it has a single
+    /// digit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1_998_000);
+    /// assert_eq!(x.trailing_zeros_base10(), 3);
+    /// assert_eq!(Infinint::from(0).trailing_zeros_base10(), 0);
+    /// ```
+    pub fn trailing_zeros_base10(&self) -> usize {
+        match self.digits_vec.iter().position(|d| *d != 0) {
+            Some(i) if decimal_digit_high(self.digits_vec[i]).unwrap() == 0 => i * 2 + 1,
+            Some(i) => i * 2,
+            None => 0,
+        }
+    }
+
+    /// Returns a vector where each element is a single digit of the Infinint, in big-endian order:
+    /// the first element is the most-significant digit, matching how the number is written.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// let d = x.digits_be();
+    /// assert_eq!(d, [1, 9, 9, 8]);
+    /// ```
+    pub fn digits_be(&self) -> Vec<u8> {
+        let mut digits = self.digits();
+        digits.reverse();
+        digits
+    }
+
+    /// Consumes the Infinint and returns its digits in little-endian order, like
+    /// [`digits()`](#method.digits). The backing allocation is reused where possible.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// let d = x.into_digits();
+    /// assert_eq!(d, [8, 9, 9, 1]);
+    /// ```
+    pub fn into_digits(self) -> Vec<u8> {
+        let mut digits = self.digits_vec;
+        let len = digits.len();
+        digits.resize(len * 2, 0);
+
+        // unpack from the back so that no byte is overwritten before it has been read
+        for i in (0..len).rev() {
+            let (high, low) = decimal_digits(digits[i]).unwrap();
+            digits[i * 2] = high;
+            digits[i * 2 + 1] = low;
+        }
+        while digits.len() > 1 && digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        digits
+    }
+
+    /// Parses a decimal string with an optional leading `+` or `-` sign, like `FromStr`, but also
+    /// accepts any of `separators` between digits. Each separator must sit between two digits,
+    /// though the group sizes are not checked. This reads back the output of `Display` and
+    /// [`format_grouped`](#method.format_grouped).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from_str_grouped("-1,234,567", &[',']).unwrap();
+    /// assert_eq!(x, Infinint::from(-1_234_567));
+    ///
+    /// let y = Infinint::from_str_grouped("12_34 567", &['_', ' ']).unwrap();
+    /// assert_eq!(y, Infinint::from(1_234_567));
+    /// ```
+    pub fn from_str_grouped(
+        src: &str,
+        separators: &[char],
+    ) -> Result<Infinint, ParseInfinintError> {
+        let (negative, offset) = Infinint::parse_sign(src);
+
+        let mut digits = Vec::with_capacity(src.len() - offset);
+        let mut last_separator = None;
+        for (position, c) in src.chars().enumerate().skip(offset) {
+            match c.to_digit(10) {
+                Some(d) => {
+                    digits.push(d as u8);
+                    last_separator = None;
+                }
+                // a separator must follow a digit, and the check below requires another digit
+                None if separators.contains(&c)
+                    && !digits.is_empty()
+                    && last_separator.is_none() =>
+                {
+                    last_separator = Some((position, c));
+                }
+                None => return Err(ParseInfinintError::InvalidDigit { position, found: c }),
+            }
+        }
+        if let Some((position, found)) = last_separator {
+            return Err(ParseInfinintError::InvalidDigit { position, found });
+        }
+        if digits.is_empty() {
+            return Err(ParseInfinintError::Empty);
+        }
+
+        // the string is most-significant first, but the digits are stored little-endian
+        digits.reverse();
+        let digits_vec = Infinint::digits_vec_from_digits(&digits);
+        let negative = negative && digits_vec != [0];
+
+        Ok(Infinint {
+            negative,
+            digits_vec,
+        })
+    }
+
+    /// Parses ASCII decimal digits with an optional leading `+` or `-` sign, like `FromStr` but
+    /// reading bytes directly, so input such as a network buffer needs no UTF-8 check first.
+    ///
+    /// A byte which is not a digit is reported by its index, with `found` being the byte read as
+    /// a Latin-1 character.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, ParseInfinintError};
+    /// assert_eq!(Infinint::from_ascii(b"-12345"), Ok(Infinint::from(-12345)));
+    /// assert_eq!(
+    ///     Infinint::from_ascii(b"12x45"),
+    ///     Err(ParseInfinintError::InvalidDigit { position: 2, found: 'x' })
+    /// );
+    /// ```
+    pub fn from_ascii(bytes: &[u8]) -> Result<Infinint, ParseInfinintError> {
+        let (negative, offset) = match bytes.first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
         };
+        let digit_bytes = &bytes[offset..];
+        if let Some(i) = digit_bytes.iter().position(|byte| !byte.is_ascii_digit()) {
+            return Err(ParseInfinintError::InvalidDigit {
+                position: offset + i,
+                found: char::from(digit_bytes[i]),
+            });
+        }
+        if digit_bytes.is_empty() {
+            return Err(ParseInfinintError::Empty);
+        }
 
-        if n_negative == false && m_negative == true {
-            return Infinint::infinint_subtract(n, m, negate_n, !negate_m, negate_result);
-        } else if n_negative == true && m_negative == false {
-            return Infinint::infinint_subtract(m, n, negate_m, !negate_n, negate_result);
-        } // otherwise, negative can be determined later
+        // the bytes are most-significant first, but the digits are stored little-endian
+        let digits: Vec<u8> = digit_bytes.iter().rev().map(|byte| byte - b'0').collect();
+        let digits_vec = Infinint::digits_vec_from_digits(&digits);
+        let negative = negative && digits_vec != [0];
 
-        let result_digits_vec =
-            Infinint::op_digits(&n.digits_vec, &m.digits_vec, decimal_add_with_carry);
+        Ok(Infinint {
+            negative,
+            digits_vec,
+        })
+    }
 
-        let result_negative = if negate_result == false {
-            n_negative
+    /// Parses a decimal string like `FromStr`, but first rejects any input with more than
+    /// `max_digits` characters after its sign, before anything is allocated. Leading zeros count
+    /// toward the limit. This bounds the work done on untrusted input.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, ParseInfinintError};
+    /// assert_eq!(Infinint::from_str_max("-1998", 4), Ok(Infinint::from(-1998)));
+    /// assert_eq!(
+    ///     Infinint::from_str_max("19980", 4),
+    ///     Err(ParseInfinintError::TooLong { max_digits: 4 })
+    /// );
+    /// ```
+    pub fn from_str_max(src: &str, max_digits: usize) -> Result<Infinint, ParseInfinintError> {
+        let (_, offset) = Infinint::parse_sign(src);
+        // the sign is ASCII, so its character count is also its length in bytes
+        if src.len() - offset > max_digits {
+            return Err(ParseInfinintError::TooLong { max_digits });
+        }
+        Infinint::from_str_grouped(src, &[])
+    }
+
+    /// Parses a string of digits in the given `radix`, with an optional leading `+` or `-` sign.
+    /// Digits above 9 are the letters `a` to `z` in either case, as for `u32::from_str_radix`.
+    ///
+    /// An error is returned if `radix` is not between 2 and 36, or if the string is empty or
+    /// contains a character which is not a digit in that radix.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, ParseInfinintError};
+    /// assert_eq!(Infinint::from_str_radix("-ff", 16), Ok(Infinint::from(-255)));
+    /// assert_eq!(Infinint::from_str_radix("101", 2), Ok(Infinint::from(5)));
+    /// assert_eq!(
+    ///     Infinint::from_str_radix("1", 37),
+    ///     Err(ParseInfinintError::InvalidRadix(37))
+    /// );
+    /// ```
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Infinint, ParseInfinintError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseInfinintError::InvalidRadix(radix));
+        }
+        let (negative, offset) = Infinint::parse_sign(src);
+        Infinint::parse_radix_digits(src, radix, negative, offset)
+    }
+
+    fn parse_radix_digits(
+        src: &str,
+        radix: u32,
+        negative: bool,
+        offset: usize,
+    ) -> Result<Infinint, ParseInfinintError> {
+        // the decimal digits of the value so far, little-endian; each new digit multiplies them
+        // by the radix and adds itself in
+        let mut digits = vec![0u8];
+        let mut empty = true;
+        for (position, c) in src.chars().enumerate().skip(offset) {
+            let d = c
+                .to_digit(radix)
+                .ok_or(ParseInfinintError::InvalidDigit { position, found: c })?;
+            Infinint::mul_add_unpacked(&mut digits, radix, d);
+            empty = false;
+        }
+        if empty {
+            return Err(ParseInfinintError::Empty);
+        }
+
+        let digits_vec = Infinint::digits_vec_from_digits(&digits);
+        let negative = negative && digits_vec != [0];
+
+        Ok(Infinint {
+            negative,
+            digits_vec,
+        })
+    }
+
+    fn mul_add_unpacked(digits: &mut Vec<u8>, factor: u32, addend: u32) {
+        // sets little-endian decimal `digits` to `digits * factor + addend`; both are small enough
+        // that `9 * factor + carry` fits in a u32
+        let mut carry = addend;
+        for digit in digits.iter_mut() {
+            let x = u32::from(*digit) * factor + carry;
+            *digit = (x % 10) as u8;
+            carry = x / 10;
+        }
+        while carry > 0 {
+            digits.push((carry % 10) as u8);
+            carry /= 10;
+        }
+    }
+
+    fn parse_sign(src: &str) -> (bool, usize) {
+        // returns whether the string is negative and how many characters its sign takes
+        match src.chars().next() {
+            Some('-') => (true, 1),
+            Some('+') => (false, 1),
+            _ => (false, 0),
+        }
+    }
+
+    fn parse_radix_prefix(src: &str) -> Option<u32> {
+        // the radix named by a `0x`, `0o` or `0b` prefix at the start of `src`, in either case
+        match src.get(..2) {
+            Some("0x") | Some("0X") => Some(16),
+            Some("0o") | Some("0O") => Some(8),
+            Some("0b") | Some("0B") => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Returns the `f64` closest to the Infinint, or an infinity of the same sign when its
+    /// magnitude is too large to represent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1998);
+    /// assert_eq!(x.to_f64(), -1998.0);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        // f64::MAX is below 10^309, so anything longer is out of range; otherwise parsing the
+        // decimal digits rounds correctly, which is hard to match with arithmetic on f64s
+        let magnitude = if self.num_digits() > 309 {
+            f64::INFINITY
         } else {
-            !n_negative
+            let digits: String = self
+                .digits_be()
+                .iter()
+                .map(|d| char::from(b'0' + d))
+                .collect();
+            digits.parse().unwrap()
         };
 
-        Infinint {
-            negative: result_negative,
-            digits_vec: result_digits_vec,
+        if self.negative && !self.is_zero() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns the Infinint as a `u128`, clamped to `0` when negative and to `u128::MAX` when
+    /// too large.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(1998).to_u128_saturating(), 1998);
+    /// assert_eq!(Infinint::from(-1998).to_u128_saturating(), 0);
+    /// assert_eq!(Infinint::factorial(40).to_u128_saturating(), u128::MAX);
+    /// ```
+    pub fn to_u128_saturating(&self) -> u128 {
+        if self.negative && !self.is_zero() {
+            0
+        } else {
+            self.magnitude_u128().unwrap_or(u128::MAX)
+        }
+    }
+
+    /// Returns the Infinint as an `i128`, clamped to `i128::MIN` or `i128::MAX` when out of
+    /// range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(-1998).to_i128_saturating(), -1998);
+    /// assert_eq!(Infinint::factorial(40).to_i128_saturating(), i128::MAX);
+    /// assert_eq!((-Infinint::factorial(40)).to_i128_saturating(), i128::MIN);
+    /// ```
+    pub fn to_i128_saturating(&self) -> i128 {
+        let magnitude = self.magnitude_u128().unwrap_or(u128::MAX);
+        if self.negative {
+            // i128::MIN has a magnitude one more than i128::MAX, so it has no positive i128 to negate
+            if magnitude >= i128::MIN.unsigned_abs() {
+                i128::MIN
+            } else {
+                -(magnitude as i128)
+            }
+        } else {
+            i128::try_from(magnitude).unwrap_or(i128::MAX)
+        }
+    }
+
+    /// Returns true if the Infinint is between `u64::MIN` and `u64::MAX`, so converting it to a
+    /// `u64` would not lose anything.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(u64::MAX).fits_u64());
+    /// assert!(!Infinint::from(-1).fits_u64());
+    /// ```
+    pub fn fits_u64(&self) -> bool {
+        self.fits_between(Infinint::from(u64::MIN), Infinint::from(u64::MAX))
+    }
+
+    /// Returns true if the Infinint is between `i64::MIN` and `i64::MAX`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(i64::MIN).fits_i64());
+    /// assert!(!Infinint::from(u64::MAX).fits_i64());
+    /// ```
+    pub fn fits_i64(&self) -> bool {
+        self.fits_between(Infinint::from(i64::MIN), Infinint::from(i64::MAX))
+    }
+
+    /// Returns true if the Infinint is between `u128::MIN` and `u128::MAX`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(u128::MAX).fits_u128());
+    /// assert!(!Infinint::from(-1).fits_u128());
+    /// ```
+    pub fn fits_u128(&self) -> bool {
+        self.fits_between(Infinint::from(u128::MIN), Infinint::from(u128::MAX))
+    }
+
+    /// Returns true if the Infinint is between `i128::MIN` and `i128::MAX`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(i128::MIN).fits_i128());
+    /// assert!(!Infinint::from(u128::MAX).fits_i128());
+    /// ```
+    pub fn fits_i128(&self) -> bool {
+        self.fits_between(Infinint::from(i128::MIN), Infinint::from(i128::MAX))
+    }
+
+    fn fits_between(&self, min: Infinint, max: Infinint) -> bool {
+        *self >= min && *self <= max
+    }
+
+    fn magnitude_u128(&self) -> Option<u128> {
+        // None when the magnitude does not fit
+        self.digits_vec.iter().rev().try_fold(0u128, |acc, byte| {
+            let (high, low) = decimal_digits(*byte).unwrap();
+            acc.checked_mul(100)?
+                .checked_add(u128::from(low) * 10 + u128::from(high))
+        })
+    }
+
+    /// Returns the decimal digits of the Infinint's magnitude as a string, with no sign, grouping
+    /// or leading zeros. Zero is `"0"`. This is suitable as a canonical key for the magnitude.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1_234_567);
+    /// assert_eq!(x.to_plain_string(), "1234567");
+    /// assert_eq!(x.to_string(), "-1,234,567");
+    /// ```
+    pub fn to_plain_string(&self) -> String {
+        let num_digits = self.num_digits();
+        (0..num_digits)
+            .rev()
+            .map(|i| char::from(b'0' + self.digit_at(i)))
+            .collect()
+    }
+
+    /// Returns the decimal digits of the Infinint's magnitude in exactly `width` characters, for
+    /// tabular output. Shorter values are padded on the left with `pad`. Longer ones keep their
+    /// `width - 1` most-significant digits followed by `…`, so a truncated value never passes for
+    /// a whole one. The sign is not included, as with [`to_plain_string()`](#method.to_plain_string).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(42);
+    /// assert_eq!(x.to_fixed_width(5, '0'), "00042");
+    /// assert_eq!(x.to_fixed_width(5, ' '), "   42");
+    /// assert_eq!(Infinint::from(-1_234_567).to_fixed_width(5, ' '), "1234…");
+    /// ```
+    pub fn to_fixed_width(&self, width: usize, pad: char) -> String {
+        let num_digits = self.num_digits();
+        if num_digits > width {
+            let mut truncated: String = (0..num_digits)
+                .rev()
+                .take(width.saturating_sub(1))
+                .map(|i| char::from(b'0' + self.digit_at(i)))
+                .collect();
+            if width > 0 {
+                truncated.push('…');
+            }
+            return truncated;
+        }
+
+        let mut padded: String = (num_digits..width).map(|_| pad).collect();
+        padded.push_str(&self.to_plain_string());
+        padded
+    }
+
+    /// Returns the Infinint in engineering notation: a mantissa of `sig_figs` significant digits,
+    /// truncated toward zero, and an exponent which is a multiple of 3. The mantissa always keeps
+    /// its 1 to 3 integer digits, padded with zeros if `sig_figs` is smaller, and a value with
+    /// fewer than `sig_figs` digits gets trailing zeros. A `sig_figs` of zero counts as one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(123_450_000).to_engineering_string(5), "123.45e6");
+    /// assert_eq!(Infinint::from(-1000).to_engineering_string(3), "-1.00e3");
+    /// assert_eq!(Infinint::from(999).to_engineering_string(2), "990e0");
+    /// ```
+    pub fn to_engineering_string(&self, sig_figs: usize) -> String {
+        let sig_figs = cmp::max(sig_figs, 1);
+        let digits = self.digits_be();
+        let exponent = (digits.len() - 1) / 3 * 3;
+        let integer_digits = digits.len() - exponent;
+
+        let mut engineering = String::with_capacity(cmp::max(sig_figs, integer_digits) + 8);
+        if self.negative {
+            engineering.push('-');
+        }
+        for i in 0..cmp::max(sig_figs, integer_digits) {
+            if i == integer_digits {
+                engineering.push('.');
+            }
+            let digit = if i < sig_figs {
+                digits.get(i).copied().unwrap_or(0)
+            } else {
+                0
+            };
+            engineering.push(char::from(b'0' + digit));
+        }
+        engineering.push_str(&format!("e{}", exponent));
+        engineering
+    }
+
+    /// Returns a wrapper which displays the Infinint with `separator` between every `group_size`
+    /// digits, counting from the least-significant end. A `group_size` of zero disables grouping.
+    /// The default `Display` output groups with `','` every 3 digits.
+    ///
+    /// A precision (`{:.6}`) is the minimum number of digits to show, with zeros added in front as
+    /// needed; unlike the default `Display`, the output is never abbreviated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1_234_567);
+    /// assert_eq!(x.format_grouped('_', 3).to_string(), "-1_234_567");
+    ///
+    /// // Indian-style grouping: 3 digits, then groups of 2
+    /// let y = Infinint::from(123_456_789);
+    /// let grouped = y.format_grouped(',', 2).first_group_size(3);
+    /// assert_eq!(grouped.to_string(), "12,34,56,789");
+    /// ```
+    pub fn format_grouped(&self, separator: char, group_size: usize) -> GroupedDisplay<'_> {
+        GroupedDisplay {
+            value: self,
+            separator,
+            first_group_size: group_size,
+            group_size,
+            min_digits: 0,
+        }
+    }
+
+    /// Returns a wrapper which displays the Infinint grouped as in the Indian numbering system:
+    /// the last three digits, then every two digits, separated by commas. This is shorthand for
+    /// `format_grouped(',', 2).first_group_size(3)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(10_000_000);
+    /// assert_eq!(x.format_indian().to_string(), "1,00,00,000");
+    /// ```
+    pub fn format_indian(&self) -> GroupedDisplay<'_> {
+        self.format_grouped(',', 2).first_group_size(3)
+    }
+
+    fn fmt_grouped(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        separator: char,
+        first_group_size: usize,
+        group_size: usize,
+        min_num_digits: usize,
+    ) -> fmt::Result {
+        let separators = |num_digits: usize| {
+            if first_group_size == 0 || num_digits <= first_group_size {
+                0
+            } else {
+                // a group size of zero leaves everything past the first group in one piece
+                let rest = num_digits - first_group_size - 1;
+                1 + rest.checked_div(group_size).unwrap_or(0)
+            }
+        };
+
+        // the minimum number of digits and zero-padding to the width both apply before grouping,
+        // so the padding zeros are grouped like any other digit; a separator is never the first
+        // character, so the result may be one character wider than asked for
+        let mut digits = self.digits_be();
+        let mut num_digits = cmp::max(digits.len(), min_num_digits);
+        if let (true, Some(width)) = (f.sign_aware_zero_pad(), f.width()) {
+            let sign = (self.negative && !self.is_zero() || f.sign_plus()) as usize;
+            while sign + num_digits + separators(num_digits) < width {
+                num_digits += 1;
+            }
+        }
+        if num_digits > digits.len() {
+            let mut padded = vec![0; num_digits - digits.len()];
+            padded.extend_from_slice(&digits);
+            digits = padded;
+        }
+
+        let num_digits = digits.len();
+        let mut grouped = String::with_capacity(num_digits + separators(num_digits));
+        for (i, digit) in digits.iter().enumerate() {
+            // the number of digits still to come decides whether a group ends before this one
+            let remaining = num_digits - i;
+            if i > 0 && first_group_size > 0 && remaining >= first_group_size {
+                let past_first = remaining - first_group_size;
+                if past_first == 0 || group_size > 0 && past_first % group_size == 0 {
+                    grouped.push(separator);
+                }
+            }
+            grouped.push(char::from(b'0' + digit));
+        }
+
+        // the digits already fill the width when zero-padding, so this only adds the sign there; a
+        // zero counts as non-negative, so the `+` flag shows "+0" even for a negated zero
+        f.pad_integral(!self.negative || self.is_zero(), "", &grouped)
+    }
+
+    fn fmt_abbreviated(&self, f: &mut fmt::Formatter<'_>, significant: usize) -> fmt::Result {
+        let digits = self.digits_be();
+        let sign = if self.negative {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        let mut abbreviated = String::with_capacity(significant + 8);
+        for (i, digit) in digits[..significant].iter().enumerate() {
+            if i == 1 {
+                abbreviated.push('.');
+            }
+            abbreviated.push(char::from(b'0' + digit));
+        }
+        abbreviated.push_str(&format!("×10^{}", digits.len() - 1));
+
+        // `pad_integral` measures in bytes, which miscounts the `×`, so the padding is done here
+        let len = sign.len() + abbreviated.chars().count();
+        let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+        // zero-padding goes between the sign and the digits, any other fill outside both
+        let zero_pad = f.sign_aware_zero_pad();
+        let (fill, before, after) = if zero_pad {
+            ('0', padding, 0)
+        } else {
+            let (before, after) = match f.align() {
+                Some(fmt::Alignment::Left) => (0, padding),
+                Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+                Some(fmt::Alignment::Right) | None => (padding, 0),
+            };
+            (f.fill(), before, after)
+        };
+        if zero_pad {
+            f.write_str(sign)?;
+        }
+        for _ in 0..before {
+            write!(f, "{}", fill)?;
+        }
+        if !zero_pad {
+            f.write_str(sign)?;
+        }
+        f.write_str(&abbreviated)?;
+        for _ in 0..after {
+            write!(f, "{}", fill)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a boolean indicating if the Infinint is even. Zero is even.
+    ///
+    /// Only the least-significant decimal digit is inspected, so this runs in constant time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// assert_eq!(x.is_even(), true);
+    /// ```
+    pub fn is_even(&self) -> bool {
+        // the least-significant digit is stored in the upper nybble of the first byte
+        let ones_digit = decimal_digit_high(self.digits_vec[0]).unwrap();
+        ones_digit % 2 == 0
+    }
+
+    /// Returns a boolean indicating if the Infinint is odd.
+    ///
+    /// Only the least-significant decimal digit is inspected, so this runs in constant time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-137);
+    /// assert_eq!(x.is_odd(), true);
+    /// ```
+    pub fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// Returns a boolean indicating if the Infinint is zero, regardless of sign.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::new();
+    /// assert_eq!(x.is_zero(), true);
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        // a nonzero value always has a nonzero final byte, so this usually returns immediately
+        self.digits_vec.iter().rev().all(|d| *d == 0)
+    }
+
+    /// Returns the sum of all decimal digits of the Infinint, ignoring sign.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-12345);
+    /// assert_eq!(x.digit_sum(), Infinint::from(15));
+    /// ```
+    pub fn digit_sum(&self) -> Infinint {
+        Infinint::from(self.digit_sum_u128())
+    }
+
+    /// Returns the digital root of the Infinint, ignoring sign: the single digit obtained by
+    /// repeatedly summing decimal digits. This is between 1 and 9, or 0 for zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(12345);
+    /// assert_eq!(x.digital_root(), 6);
+    /// ```
+    pub fn digital_root(&self) -> u8 {
+        if self.is_zero() {
+            return 0;
+        }
+
+        match (self.digit_sum_u128() % 9) as u8 {
+            0 => 9,
+            root => root,
+        }
+    }
+
+    /// Returns the sum of the squares of the Infinint's decimal digits, ignoring sign.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-19);
+    /// assert_eq!(x.sum_of_digit_squares(), Infinint::from(82));
+    /// ```
+    pub fn sum_of_digit_squares(&self) -> Infinint {
+        let sum: u128 = self.digit_iter().map(|d| u128::from(d * d)).sum();
+        Infinint::from(sum)
+    }
+
+    /// Returns a boolean indicating if the Infinint is a happy number, ignoring sign: repeatedly
+    /// replacing it with [`sum_of_digit_squares()`](#method.sum_of_digit_squares) reaches 1.
+    /// Every other positive value falls into the cycle through 4 instead. Zero is not happy.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert!(Infinint::from(19).is_happy());
+    /// assert!(!Infinint::from(2).is_happy());
+    /// ```
+    pub fn is_happy(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+
+        // after one step the value has at most a few digits, so this settles quickly
+        let (one, four) = (Infinint::one(), Infinint::from(4));
+        let mut x = self.sum_of_digit_squares();
+        while x != one && x != four {
+            x = x.sum_of_digit_squares();
+        }
+        x == one
+    }
+
+    fn digit_sum_u128(&self) -> u128 {
+        // read the nybbles directly rather than allocating through digits()
+        self.digits_vec
+            .iter()
+            .map(|byte| decimal_digits(*byte).unwrap())
+            .map(|(high, low)| u128::from(high) + u128::from(low))
+            .sum()
+    }
+
+    /// Returns a boolean indicating if the Infinint is a multiple of `other`. Only zero is a
+    /// multiple of zero.
+    ///
+    /// Divisibility by 1, 2, 3, 5, 9 and 10 is decided from the digits, without dividing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// assert!(x.is_divisible_by(&Infinint::from(-9)));
+    /// assert!(!x.is_divisible_by(&Infinint::from(5)));
+    /// ```
+    pub fn is_divisible_by(&self, other: &Infinint) -> bool {
+        if other.is_zero() {
+            return self.is_zero();
+        }
+
+        // a divisor below 100 fits in a single byte
+        if other.digits_vec.len() == 1 {
+            let (ones, tens) = decimal_digits(other.digits_vec[0]).unwrap();
+            let ones_digit = decimal_digit_high(self.digits_vec[0]).unwrap();
+            match tens * 10 + ones {
+                1 => return true,
+                2 => return ones_digit % 2 == 0,
+                5 => return ones_digit % 5 == 0,
+                10 => return ones_digit == 0,
+                3 => return self.digit_sum_u128() % 3 == 0,
+                9 => return self.digit_sum_u128() % 9 == 0,
+                _ => (),
+            }
+        }
+
+        (self % other).is_zero()
+    }
+
+    /// Returns the floor of the square root of the Infinint.
+    ///
+    /// # Panics
+    /// Panics if the Infinint is negative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(143);
+    /// assert_eq!(x.isqrt(), Infinint::from(11));
+    /// ```
+    pub fn isqrt(&self) -> Infinint {
+        if self.is_zero() {
+            return Infinint::new();
+        }
+        assert!(
+            !self.negative,
+            "cannot take the square root of a negative number"
+        );
+
+        // start from a power of ten no smaller than the root, then apply Newton's method; the
+        // sequence decreases monotonically until it reaches the floor of the root
+        let two = Infinint::from(2);
+        let mut root_digits = vec![0; (self.num_digits() + 1) / 2];
+        root_digits.push(1);
+        let mut x = Infinint {
+            negative: false,
+            digits_vec: Infinint::digits_vec_from_digits(&root_digits),
+        };
+
+        loop {
+            let y = &(&x + &(self / &x)) / &two;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Returns the greatest common divisor of two Infinints. The result is never negative, and the
+    /// greatest common divisor of zero and zero is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(48);
+    /// let y = Infinint::from(-36);
+    /// assert_eq!(x.gcd(&y), Infinint::from(12));
+    /// ```
+    pub fn gcd(&self, other: &Infinint) -> Infinint {
+        let mut a = self.abs();
+        let mut b = other.abs();
+
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+
+        a
+    }
+
+    /// Returns the least common multiple of two Infinints. The result is never negative, and the
+    /// least common multiple of zero and anything is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(4);
+    /// let y = Infinint::from(-6);
+    /// assert_eq!(x.lcm(&y), Infinint::from(12));
+    /// ```
+    pub fn lcm(&self, other: &Infinint) -> Infinint {
+        if self.is_zero() || other.is_zero() {
+            return Infinint::new();
+        }
+
+        // divide before multiplying so the intermediate value is no larger than the result
+        let quotient = &self.abs() / &self.gcd(other);
+        &quotient * &other.abs()
+    }
+
+    /// Returns the Infinint plus one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(-1).succ(), Infinint::from(0));
+    /// ```
+    pub fn succ(&self) -> Infinint {
+        self + &Infinint::from(1)
+    }
+
+    /// Returns the Infinint minus one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!(Infinint::from(0).pred(), Infinint::from(-1));
+    /// ```
+    pub fn pred(&self) -> Infinint {
+        self - &Infinint::from(1)
+    }
+
+    /// Adds one to the Infinint in place, only allocating when the number of digits grows.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut x = Infinint::from(99);
+    /// x.increment();
+    /// assert_eq!(x, Infinint::from(100));
+    /// ```
+    pub fn increment(&mut self) {
+        if self.negative && !self.is_zero() {
+            // moving toward zero
+            Infinint::decrement_digits_vec(&mut self.digits_vec);
+            self.negative = !self.is_zero();
+        } else {
+            Infinint::increment_digits_vec(&mut self.digits_vec);
+            self.negative = false;
+        }
+    }
+
+    /// Subtracts one from the Infinint in place, only allocating when the number of digits grows.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut x = Infinint::from(0);
+    /// x.decrement();
+    /// assert_eq!(x, Infinint::from(-1));
+    /// ```
+    pub fn decrement(&mut self) {
+        if self.negative || self.is_zero() {
+            // moving away from zero, so zero becomes -1
+            Infinint::increment_digits_vec(&mut self.digits_vec);
+            self.negative = true;
+        } else {
+            Infinint::decrement_digits_vec(&mut self.digits_vec);
+        }
+    }
+
+    /// Returns an iterator over the half-open range `[start, end)` in ascending order. The range
+    /// is empty if `start >= end`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let sum = Infinint::range(Infinint::from(1), Infinint::from(101))
+    ///     .fold(Infinint::new(), |acc, x| &acc + &x);
+    /// assert_eq!(sum, Infinint::from(5050));
+    /// ```
+    pub fn range(start: Infinint, end: Infinint) -> impl Iterator<Item = Infinint> {
+        let first = if start < end { Some(start) } else { None };
+        iter::successors(first, move |x| {
+            let next = x.succ();
+            if next < end {
+                Some(next)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the absolute difference `|self - other|`, which is never negative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-3);
+    /// let y = Infinint::from(10);
+    /// assert_eq!(x.abs_diff(&y), Infinint::from(13));
+    /// ```
+    pub fn abs_diff(&self, other: &Infinint) -> Infinint {
+        // subtract the smaller from the larger so that the result needs no negation
+        match self.cmp(other) {
+            cmp::Ordering::Less => other - self,
+            _ => self - other,
+        }
+    }
+
+    /// Subtracts `other`, returning the magnitude of the difference and whether the subtraction
+    /// went below zero. For non-negative operands this models unsigned subtraction that reports a
+    /// borrow instead of producing a negative number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(5);
+    /// let y = Infinint::from(3);
+    /// assert_eq!(x.overflowing_sub(&y), (Infinint::from(2), false));
+    /// assert_eq!(y.overflowing_sub(&x), (Infinint::from(2), true));
+    /// ```
+    pub fn overflowing_sub(&self, other: &Infinint) -> (Infinint, bool) {
+        let mut difference = self - other;
+        let borrowed = difference.negative && !difference.is_zero();
+        difference.negative = false;
+        (difference, borrowed)
+    }
+
+    /// Compares the absolute values of two Infinints, ignoring their signs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// # use std::cmp::Ordering;
+    /// let x = Infinint::from(-5);
+    /// assert_eq!(x.cmp_abs(&Infinint::from(3)), Ordering::Greater);
+    /// ```
+    pub fn cmp_abs(&self, other: &Infinint) -> cmp::Ordering {
+        // a canonical digits_vec has no most-significant zero bytes, so the longer one is larger
+        self.digits_vec
+            .len()
+            .cmp(&other.digits_vec.len())
+            .then_with(|| Infinint::cmp_digits(&self.digits_vec, &other.digits_vec))
+    }
+
+    /// Restricts the Infinint to the range `[min, max]`, consistent with `Ord`.
+    ///
+    /// In debug builds, this panics if `min > max`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// let y = x.clamp(Infinint::from(0), Infinint::from(100));
+    /// assert_eq!(y, Infinint::from(100));
+    /// ```
+    pub fn clamp(self, min: Infinint, max: Infinint) -> Infinint {
+        debug_assert!(min <= max, "clamp called with min > max");
+
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of two Infinints, consistent with `Ord`. If they are equal, `n` is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::min(Infinint::from(-5), Infinint::from(3));
+    /// assert_eq!(x, Infinint::from(-5));
+    /// ```
+    pub fn min(n: Infinint, m: Infinint) -> Infinint {
+        if m < n {
+            m
+        } else {
+            n
+        }
+    }
+
+    /// Returns the larger of two Infinints, consistent with `Ord`. If they are equal, `m` is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::max(Infinint::from(-5), Infinint::from(3));
+    /// assert_eq!(x, Infinint::from(3));
+    /// ```
+    pub fn max(n: Infinint, m: Infinint) -> Infinint {
+        if m < n {
+            n
+        } else {
+            m
+        }
+    }
+
+    /// Multiplies the Infinint by a single decimal digit `d`, in one pass over the packed digits.
+    ///
+    /// # Panics
+    /// Panics if `d` is not between 0 and 9.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(12345);
+    /// assert_eq!(x.mul_digit(3), Infinint::from(37035));
+    /// ```
+    pub fn mul_digit(&self, d: u8) -> Infinint {
+        assert!(d < 10, "digit out of range: {}", d);
+
+        let mut carry = 0;
+        let mut digits_vec = Vec::with_capacity(self.digits_vec.len() + 1);
+        for byte in self.digits_vec.iter() {
+            let (high, low) = decimal_digits(*byte).unwrap();
+            let (upper_result_digit, new_carry) = decimal_mul_with_carry(high, d, carry);
+            let (lower_result_digit, new_carry) = decimal_mul_with_carry(low, d, new_carry);
+            carry = new_carry;
+            digits_vec.push((upper_result_digit << 4) | lower_result_digit);
+        }
+        if carry > 0 {
+            digits_vec.push(carry << 4);
+        }
+        // multiplying by zero leaves a run of zero bytes
+        Infinint::trim_digits_vec(&mut digits_vec);
+        let negative = self.negative && digits_vec != [0];
+
+        Infinint {
+            negative,
+            digits_vec,
+        }
+    }
+
+    /// Raises the Infinint to the power `exp`, using exponentiation by squaring. Any value to the
+    /// power zero is one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-3);
+    /// assert_eq!(x.pow(5), Infinint::from(-243));
+    /// ```
+    pub fn pow(&self, exp: u32) -> Infinint {
+        let mut result = Infinint::from(1);
+        let mut base = self.clone();
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    /// Raises the Infinint to the power `exp`, like [`pow()`](#method.pow) but with an Infinint
+    /// exponent, by square-and-multiply over the bits of `exp`.
+    ///
+    /// The result grows with `exp`, so this is only practical for small bases or moderate
+    /// exponents; 0, 1 and -1 can be raised to any power.
+    ///
+    /// # Panics
+    /// Panics if `exp` is negative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-3);
+    /// assert_eq!(x.pow_big(&Infinint::from(5)), Infinint::from(-243));
+    /// ```
+    pub fn pow_big(&self, exp: &Infinint) -> Infinint {
+        assert!(
+            !exp.negative || exp.is_zero(),
+            "cannot raise to a negative power"
+        );
+
+        let mut result = Infinint::from(1);
+        let mut base = self.clone();
+        let mut exp = exp.abs();
+
+        while !exp.is_zero() {
+            if exp.is_odd() {
+                result *= &base;
+            }
+            exp = exp.divmod_small(2).0;
+            if !exp.is_zero() {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    /// Divides by `other`, returning the quotient only if the division is exact, with a remainder
+    /// of zero, and `None` otherwise. The quotient and remainder come from a single division.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(12);
+    /// assert_eq!(x.divide_exact(&Infinint::from(4)), Some(Infinint::from(3)));
+    /// assert_eq!(x.divide_exact(&Infinint::from(5)), None);
+    /// ```
+    pub fn divide_exact(&self, other: &Infinint) -> Option<Infinint> {
+        let (quotient, remainder) = Infinint::infinint_divmod(self, other);
+        if remainder.is_zero() {
+            Some(quotient)
+        } else {
+            None
+        }
+    }
+
+    /// Divides by `other` and returns the result as a mixed number for display: the whole part,
+    /// then the remainder over the divisor, as in `"3 2/5"` for 17 / 5. The sign comes first and
+    /// applies to the whole value, so -17 / 5 is `"-3 2/5"`. An exact quotient is shown alone, and
+    /// a whole part of zero is left out. Each number is formatted like `Display`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(17);
+    /// assert_eq!(x.divmod_display(&Infinint::from(5)), "3 2/5");
+    /// assert_eq!(x.divmod_display(&Infinint::from(-20)), "-17/20");
+    /// assert_eq!(x.divmod_display(&Infinint::from(17)), "1");
+    /// ```
+    pub fn divmod_display(&self, other: &Infinint) -> String {
+        let (quotient, remainder) = Infinint::infinint_divmod(self, other);
+        if remainder.is_zero() {
+            return format!("{}", quotient);
+        }
+
+        let sign = if self.negative != other.negative {
+            "-"
+        } else {
+            ""
+        };
+        let fraction = format!("{}/{}", remainder.abs(), other.abs());
+        if quotient.is_zero() {
+            format!("{}{}", sign, fraction)
+        } else {
+            format!("{}{} {}", sign, quotient.abs(), fraction)
+        }
+    }
+
+    /// Divides by a machine-word `divisor`, returning the quotient and the magnitude of the
+    /// remainder. This takes a single pass over the digits, so it is much faster than dividing by
+    /// an Infinint.
+    ///
+    /// As with `/`, the quotient is truncated toward zero; the remainder is that of the
+    /// magnitudes, so `self` is `quotient * divisor - remainder` when it is negative.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let (q, r) = Infinint::from(1998).divmod_small(7);
+    /// assert_eq!((q, r), (Infinint::from(285), 3));
+    ///
+    /// let (q, r) = Infinint::from(-1998).divmod_small(7);
+    /// assert_eq!((q, r), (Infinint::from(-285), 3));
+    /// ```
+    pub fn divmod_small(&self, divisor: u32) -> (Infinint, u32) {
+        assert!(divisor != 0, "attempt to divide by zero");
+
+        // the running remainder is below the divisor, so appending a digit stays well within u64
+        let divisor = u64::from(divisor);
+        let mut remainder = 0;
+        let mut quotient_digits_vec = vec![0; self.digits_vec.len()];
+        for (i, byte) in self.digits_vec.iter().enumerate().rev() {
+            let (high, low) = decimal_digits(*byte).unwrap();
+
+            remainder = remainder * 10 + u64::from(low);
+            let quotient_low = (remainder / divisor) as u8;
+            remainder %= divisor;
+
+            remainder = remainder * 10 + u64::from(high);
+            let quotient_high = (remainder / divisor) as u8;
+            remainder %= divisor;
+
+            quotient_digits_vec[i] = (quotient_high << 4) | quotient_low;
+        }
+        Infinint::trim_digits_vec(&mut quotient_digits_vec);
+        let negative = self.negative && quotient_digits_vec != [0];
+
+        let quotient = Infinint {
+            negative,
+            digits_vec: quotient_digits_vec,
+        };
+        (quotient, remainder as u32)
+    }
+
+    /// Returns the least non-negative remainder of dividing by `modulus`, which lies in
+    /// `[0, |modulus|)` whatever the signs of the operands.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-7);
+    /// assert_eq!(x.rem_euclid(&Infinint::from(3)), Infinint::from(2));
+    /// assert_eq!(&x % &Infinint::from(3), Infinint::from(-1));
+    /// ```
+    pub fn rem_euclid(&self, modulus: &Infinint) -> Infinint {
+        // the truncated remainder takes the sign of the dividend, so it only needs fixing up when
+        // that is negative
+        let remainder = self % modulus;
+        if remainder.negative {
+            &remainder + &modulus.abs()
+        } else {
+            remainder
+        }
+    }
+
+    /// Returns the quotient of Euclidean division, so that
+    /// `self == self.div_euclid(divisor) * divisor + self.rem_euclid(divisor)` with a
+    /// non-negative remainder.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-7);
+    /// assert_eq!(x.div_euclid(&Infinint::from(3)), Infinint::from(-3));
+    /// assert_eq!(&x / &Infinint::from(3), Infinint::from(-2));
+    /// ```
+    pub fn div_euclid(&self, divisor: &Infinint) -> Infinint {
+        // a negative truncated remainder means the quotient went one step too far toward zero
+        let (mut quotient, remainder) = Infinint::infinint_divmod(self, divisor);
+        if remainder.negative {
+            if divisor.negative {
+                quotient.increment();
+            } else {
+                quotient.decrement();
+            }
+        }
+        quotient
+    }
+
+    /// Computes `self` raised to the power `exp`, modulo `modulus`, by square-and-multiply. The
+    /// result is the least non-negative residue, so it lies in `[0, |modulus|)` even when `self`
+    /// is negative.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero or `exp` is negative.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(2);
+    /// let y = x.pow_mod(&Infinint::from(10), &Infinint::from(1000));
+    /// assert_eq!(y, Infinint::from(24));
+    /// ```
+    pub fn pow_mod(&self, exp: &Infinint, modulus: &Infinint) -> Infinint {
+        assert!(!modulus.is_zero(), "attempt to reduce modulo zero");
+        assert!(
+            !exp.negative || exp.is_zero(),
+            "cannot raise to a negative power modulo a number"
+        );
+
+        let modulus = modulus.abs();
+        // everything reduced is non-negative, so modulo 10^k it is just the last k digits, which
+        // is far cheaper than a division
+        let num_digits = modulus.num_digits();
+        let pow10 = modulus.trailing_zeros_base10() == num_digits - 1
+            && modulus.digit_at(num_digits - 1) == 1;
+        let reduce = |x: Infinint| {
+            if pow10 {
+                x.mod_pow10(num_digits - 1)
+            } else {
+                &x % &modulus
+            }
+        };
+
+        let two = Infinint::from(2);
+        let mut result = reduce(Infinint::from(1));
+        let mut base = self.rem_euclid(&modulus);
+        let mut exp = exp.abs();
+
+        // reduce after every step so that no intermediate value exceeds modulus^2
+        while !exp.is_zero() {
+            if exp.is_odd() {
+                result = reduce(&result * &base);
+            }
+            exp = &exp / &two;
+            if !exp.is_zero() {
+                base = reduce(&base * &base);
+            }
+        }
+
+        result
+    }
+
+    /// Multiplies two Infinints, returning `None` if the product would have more than
+    /// [`MAX_CHECKED_DIGITS`](#associatedconstant.MAX_CHECKED_DIGITS) digits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(12);
+    /// assert_eq!(x.checked_mul(&x), Some(Infinint::from(144)));
+    /// ```
+    pub fn checked_mul(&self, other: &Infinint) -> Option<Infinint> {
+        self.checked_mul_with_limit(other, Infinint::MAX_CHECKED_DIGITS)
+    }
+
+    /// Multiplies two Infinints, returning `None` if the product would have more than
+    /// `max_digits` digits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(99);
+    /// assert_eq!(x.checked_mul_with_limit(&x, 4), Some(Infinint::from(9801)));
+    /// assert_eq!(x.checked_mul_with_limit(&x, 3), None);
+    /// ```
+    pub fn checked_mul_with_limit(&self, other: &Infinint, max_digits: usize) -> Option<Infinint> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Infinint::new());
+        }
+
+        // a product has either n + m - 1 or n + m digits, so most cases are known up front
+        let digits_bound = self.num_digits() + other.num_digits();
+        if digits_bound - 1 > max_digits {
+            return None;
+        }
+
+        let result = self * other;
+        if digits_bound > max_digits && result.num_digits() > max_digits {
+            return None;
+        }
+
+        Some(result)
+    }
+
+    /// Raises the Infinint to the power `exp`, returning `None` if the result would have more
+    /// than [`MAX_CHECKED_DIGITS`](#associatedconstant.MAX_CHECKED_DIGITS) digits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(2);
+    /// assert_eq!(x.checked_pow(10), Some(Infinint::from(1024)));
+    /// assert_eq!(x.checked_pow(u32::MAX), None);
+    /// ```
+    pub fn checked_pow(&self, exp: u32) -> Option<Infinint> {
+        self.checked_pow_with_limit(exp, Infinint::MAX_CHECKED_DIGITS)
+    }
+
+    /// Raises the Infinint to the power `exp`, returning `None` if the result would have more
+    /// than `max_digits` digits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(2);
+    /// assert_eq!(x.checked_pow_with_limit(9, 3), Some(Infinint::from(512)));
+    /// assert_eq!(x.checked_pow_with_limit(10, 3), None);
+    /// ```
+    pub fn checked_pow_with_limit(&self, exp: u32, max_digits: usize) -> Option<Infinint> {
+        // 0 and ±1 never grow, and everything else has a logarithm bounded away from zero
+        if exp == 0 || self.num_digits() == 1 && self.digits_vec[0] <= 0x10 {
+            return Some(self.pow(exp));
+        }
+
+        // |x| >= 10^(n - 1), so the result has at least exp * (n - 1) + 1 digits; this is exact for
+        // powers of ten
+        let exact_min_digits = u64::from(exp) * (self.num_digits() as u64 - 1) + 1;
+        if exact_min_digits > max_digits as u64 {
+            return None;
+        }
+
+        // otherwise bail out before doing any work when an approximation of the result, which
+        // never overestimates it, already has too many digits
+        if self.pow_lower_bound_exceeds(exp, max_digits) {
+            return None;
+        }
+
+        let mut result = Infinint::from(1);
+        let mut base = self.clone();
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul_with_limit(&base, max_digits)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                // the remaining bits need at least base^2, so refuse once that cannot fit; this
+                // is checked from the digit counts alone before squaring
+                base = base.checked_mul_with_limit(&base, max_digits)?;
+            }
+        }
+
+        Some(result)
+    }
+
+    fn pow_lower_bound_exceeds(&self, exp: u32, max_digits: usize) -> bool {
+        // runs the same square-and-multiply as `checked_pow_with_limit` on `mantissa * 10^shift`,
+        // keeping only the leading 18 digits of the mantissa; truncating always rounds down, so
+        // this is a lower bound that needs neither floats nor large multiplications
+        const MANTISSA_LIMIT: u128 = 1_000_000_000_000_000_000;
+
+        fn truncate(mut mantissa: u128, mut shift: u128) -> (u128, u128) {
+            while mantissa >= MANTISSA_LIMIT {
+                mantissa /= 10;
+                shift += 1;
+            }
+            (mantissa, shift)
+        }
+
+        fn num_digits((mut mantissa, shift): (u128, u128)) -> u128 {
+            let mut digits = shift + 1;
+            while mantissa >= 10 {
+                mantissa /= 10;
+                digits += 1;
+            }
+            digits
+        }
+
+        let digits = self.digits();
+        let leading = digits
+            .iter()
+            .rev()
+            .take(18)
+            .fold(0, |acc, d| acc * 10 + u128::from(*d));
+        let dropped = digits.len().saturating_sub(18) as u128;
+
+        let max_digits = max_digits as u128;
+        let mut result = (1, 0);
+        let mut base = (leading, dropped);
+        let mut exp = exp;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = truncate(result.0 * base.0, result.1 + base.1);
+                if num_digits(result) > max_digits {
+                    return true;
+                }
+            }
+            exp >>= 1;
+            if exp > 0 {
+                // as in the exact loop, the remaining bits need at least base^2
+                base = truncate(base.0 * base.0, base.1 * 2);
+                if num_digits(base) > max_digits {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn digits_vec_from_int(n: u128) -> Vec<u8> {
+        let mut n = n;
+
+        // count the digits with integer arithmetic, since `core` has no float logarithms
+        let mut bytes_needed = 1;
+        let mut rest = n / 100;
+        while rest > 0 {
+            bytes_needed += 1;
+            rest /= 100;
+        }
+        let mut digits_vec: Vec<u8> = Vec::with_capacity(bytes_needed);
+
+        if n > 0 {
+            while n > 0 {
+                let n_mod = (n % 10) as u8;
+                let d = n_mod << 4;
+                n /= 10;
+
+                let n_mod = (n % 10) as u8;
+                let d = n_mod | d;
+                n /= 10;
+
+                digits_vec.push(d);
+            }
+        } else {
+            digits_vec.push(0);
+        }
+
+        digits_vec
+    }
+
+    fn digits_vec_from_digits(digits: &[u8]) -> Vec<u8> {
+        let mut digits_vec: Vec<u8> = digits
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair.get(1).unwrap_or(&0))
+            .collect();
+        Infinint::trim_digits_vec(&mut digits_vec);
+
+        digits_vec
+    }
+
+    fn increment_digits_vec(digits_vec: &mut Vec<u8>) {
+        // add one to the magnitude, carrying through nines from the least-significant digit
+        for byte in digits_vec.iter_mut() {
+            if decimal_digit_high(*byte).unwrap() < 9 {
+                *byte += 0x10;
+                return;
+            }
+            *byte &= 0x0F;
+            if decimal_digit_low(*byte).unwrap() < 9 {
+                *byte += 0x01;
+                return;
+            }
+            *byte = 0;
+        }
+        digits_vec.push(0x10);
+    }
+
+    fn decrement_digits_vec(digits_vec: &mut Vec<u8>) {
+        // subtract one from a non-zero magnitude, borrowing through zeros from the
+        // least-significant digit
+        for byte in digits_vec.iter_mut() {
+            if decimal_digit_high(*byte).unwrap() > 0 {
+                *byte -= 0x10;
+                break;
+            }
+            *byte |= 0x90;
+            if decimal_digit_low(*byte).unwrap() > 0 {
+                *byte -= 0x01;
+                break;
+            }
+            *byte |= 0x09;
+        }
+        Infinint::trim_digits_vec(digits_vec);
+    }
+
+    fn trim_digits_vec(digits_vec: &mut Vec<u8>) {
+        // remove most-significant zeros, but keep at least one byte
+        while digits_vec.len() > 1 && digits_vec.last() == Some(&0) {
+            digits_vec.pop();
+        }
+        if digits_vec.is_empty() {
+            digits_vec.push(0);
+        }
+    }
+
+    /// Returns a copy of the internal nybble-packed digit bytes, in little-endian order.
+    ///
+    /// See the struct documentation for details on the packed representation. The sign is not
+    /// included; use [`negative()`](#method.negative) alongside this method.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// assert_eq!(x.to_bytes(), [0b1000_1001, 0b1001_0001]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.digits_vec.clone()
+    }
+
+    /// Returns a view of the internal nybble-packed digit bytes, in little-endian order, without
+    /// copying them. This is the same data as [`to_bytes()`](#method.to_bytes).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(1998);
+    /// assert_eq!(x.as_bytes(), [0b1000_1001, 0b1001_0001]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.digits_vec
+    }
+
+    /// Reconstructs an Infinint from a sign and nybble-packed digit bytes, as produced by
+    /// [`to_bytes()`](#method.to_bytes).
+    ///
+    /// An error is returned if the slice is empty or any nybble is not a decimal digit.
+    /// Most-significant zero bytes are trimmed, and a negative zero is normalized to positive zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, NybbleError};
+    /// let x = Infinint::from_bytes(true, &[0b1000_1001, 0b1001_0001]).unwrap();
+    /// assert_eq!(x, Infinint::from(-1998));
+    ///
+    /// let e = Infinint::from_bytes(false, &[0b1010_0000]).unwrap_err();
+    /// assert_eq!(e, NybbleError::InvalidDigit { position: 0, found: 10 });
+    /// ```
+    pub fn from_bytes(negative: bool, bytes: &[u8]) -> Result<Infinint, NybbleError> {
+        Infinint::from_parts(negative, bytes.to_vec())
+    }
+
+    /// Consumes the Infinint and returns its sign and its nybble-packed digit bytes, in
+    /// little-endian order, without copying them. [`from_parts()`](#method.from_parts) reverses
+    /// this.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let (negative, digits_vec) = Infinint::from(-1998).into_parts();
+    /// assert_eq!(negative, true);
+    /// assert_eq!(digits_vec, [0b1000_1001, 0b1001_0001]);
+    /// ```
+    pub fn into_parts(self) -> (bool, Vec<u8>) {
+        (self.negative, self.digits_vec)
+    }
+
+    /// Builds an Infinint from a sign and nybble-packed digit bytes, as returned by
+    /// [`into_parts()`](#method.into_parts), reusing the vector's allocation.
+    ///
+    /// Validation and normalization are the same as for [`from_bytes()`](#method.from_bytes).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, NybbleError};
+    /// let x = Infinint::from_parts(true, vec![0b1000_1001, 0b1001_0001]).unwrap();
+    /// assert_eq!(x, Infinint::from(-1998));
+    ///
+    /// let e = Infinint::from_parts(false, vec![0x10, 0x0C]).unwrap_err();
+    /// assert_eq!(e, NybbleError::InvalidDigit { position: 1, found: 12 });
+    /// ```
+    pub fn from_parts(negative: bool, mut digits_vec: Vec<u8>) -> Result<Infinint, NybbleError> {
+        if digits_vec.is_empty() {
+            return Err(NybbleError::Empty);
+        }
+        for (position, byte) in digits_vec.iter().enumerate() {
+            decimal_digits(*byte).map_err(|e| e.at(position))?;
+        }
+
+        Infinint::trim_digits_vec(&mut digits_vec);
+        let negative = negative && digits_vec != [0];
+
+        Ok(Infinint {
+            negative,
+            digits_vec,
+        })
+    }
+
+    /// Returns the magnitude of the Infinint as big-endian base-256 bytes, with no leading zero
+    /// bytes, like `num-bigint`'s `to_bytes_be`. Zero is `[0]`. The sign is not included; use
+    /// [`negative()`](#method.negative) alongside this method.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1998);
+    /// assert_eq!(x.to_be_base256(), [0x07, 0xCE]);
+    /// ```
+    pub fn to_be_base256(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.digits_vec.len() * 5 / 6 + 3);
+        self.to_radix_be_into(256, &mut bytes);
+        bytes
+    }
+
+    /// Writes the magnitude of the Infinint into `buf` as big-endian digits in `radix`, one digit
+    /// value per byte with no leading zeros, like `num-bigint`'s `to_radix_be`. Zero is `[0]`.
+    /// The sign is not included.
+    ///
+    /// `buf` is cleared first and its allocation reused, so converting many values through one
+    /// buffer only allocates when a result is longer than any before it. Returns the number of
+    /// digits written, or `None` without touching `buf` if `radix` is not between 2 and 256.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut buf = Vec::new();
+    /// assert_eq!(Infinint::from(-1998).to_radix_be_into(16, &mut buf), Some(3));
+    /// assert_eq!(buf, [0x7, 0xC, 0xE]);
+    ///
+    /// assert_eq!(Infinint::from(5).to_radix_be_into(2, &mut buf), Some(3));
+    /// assert_eq!(buf, [1, 0, 1]);
+    ///
+    /// assert_eq!(Infinint::from(5).to_radix_be_into(1, &mut buf), None);
+    /// ```
+    pub fn to_radix_be_into(&self, radix: u32, buf: &mut Vec<u8>) -> Option<usize> {
+        if !(2..=256).contains(&radix) {
+            return None;
+        }
+
+        // divide out as many digits at a time as the fast small divisor allows
+        let mut chunk = radix;
+        let mut chunk_digits = 1;
+        while let Some(next) = chunk.checked_mul(radix) {
+            chunk = next;
+            chunk_digits += 1;
+        }
+
+        buf.clear();
+        let mut rest = self.abs();
+        while !rest.is_zero() {
+            let (quotient, mut remainder) = rest.divmod_small(chunk);
+            for _ in 0..chunk_digits {
+                buf.push((remainder % radix) as u8);
+                remainder /= radix;
+            }
+            rest = quotient;
+        }
+        while buf.len() > 1 && buf.last() == Some(&0) {
+            buf.pop();
+        }
+        if buf.is_empty() {
+            buf.push(0);
+        }
+
+        buf.reverse();
+        Some(buf.len())
+    }
+
+    /// Constructs an Infinint from a sign and a big-endian base-256 magnitude, as produced by
+    /// [`to_be_base256()`](#method.to_be_base256) or `num-bigint`'s `to_bytes_be`. Leading zero
+    /// bytes are ignored, an empty slice is zero, and a negative zero is normalized to positive
+    /// zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from_be_base256(true, &[0x07, 0xCE]);
+    /// assert_eq!(x, Infinint::from(-1998));
+    /// ```
+    pub fn from_be_base256(negative: bool, bytes: &[u8]) -> Infinint {
+        let mut digits = vec![0];
+        for byte in bytes.iter() {
+            Infinint::mul_add_unpacked(&mut digits, 256, u32::from(*byte));
+        }
+
+        let digits_vec = Infinint::digits_vec_from_digits(&digits);
+        let negative = negative && digits_vec != [0];
+
+        Infinint {
+            negative,
+            digits_vec,
+        }
+    }
+
+    /// Encodes the Infinint in a self-describing binary format, suitable for storage: a version
+    /// byte, a sign byte, the number of packed digit bytes as an unsigned LEB128 varint, and then
+    /// the packed digit bytes as returned by [`to_bytes()`](#method.to_bytes).
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::from(-1998);
+    /// assert_eq!(x.encode(), [1, 1, 2, 0b1000_1001, 0b1001_0001]);
+    /// ```
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.digits_vec.len() + Infinint::MAX_HEADER_LEN);
+        self.encode_header(&mut bytes);
+        bytes.extend_from_slice(&self.digits_vec);
+        bytes
+    }
+
+    /// Decodes an Infinint from the format written by [`encode()`](#method.encode).
+    ///
+    /// The header is validated, the length must match the number of digit bytes exactly, and
+    /// every nybble must be a decimal digit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{DecodeError, Infinint};
+    /// let x = Infinint::from(-1998);
+    /// assert_eq!(Infinint::decode(&x.encode()), Ok(x));
+    ///
+    /// assert_eq!(Infinint::decode(&[1, 0, 2, 0x10]), Err(DecodeError::UnexpectedEnd));
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<Infinint, DecodeError> {
+        let (negative, len, header_len) = Infinint::decode_header(bytes)?;
+        let digits = &bytes[header_len..];
+        match digits.len().cmp(&len) {
+            cmp::Ordering::Less => return Err(DecodeError::UnexpectedEnd),
+            cmp::Ordering::Greater => return Err(DecodeError::TrailingBytes),
+            cmp::Ordering::Equal => (),
+        }
+
+        Infinint::from_encoded_digits(negative, digits.to_vec(), header_len)
+    }
+
+    /// Writes the Infinint to `w` in the format of [`encode()`](#method.encode), without
+    /// building the whole encoding in memory first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let mut bytes = Vec::new();
+    /// Infinint::from(-1998).write_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes, Infinint::from(-1998).encode());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut header = Vec::with_capacity(Infinint::MAX_HEADER_LEN);
+        self.encode_header(&mut header);
+        w.write_all(&header)?;
+        w.write_all(&self.digits_vec)
+    }
+
+    /// Reads one Infinint from `r` in the format of [`encode()`](#method.encode), leaving any
+    /// following bytes unread.
+    ///
+    /// An input which ends early is reported as [`UnexpectedEof`](std::io::ErrorKind), and any
+    /// other [`DecodeError`] as [`InvalidData`](std::io::ErrorKind) wrapping that error.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let bytes = Infinint::from(-1998).encode();
+    /// let x = Infinint::read_from(&mut bytes.as_slice()).unwrap();
+    /// assert_eq!(x, Infinint::from(-1998));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_from<R: io::Read>(r: &mut R) -> io::Result<Infinint> {
+        use io::Read;
+
+        let into_io_error = |e: DecodeError| match e {
+            DecodeError::UnexpectedEnd => io::Error::from(io::ErrorKind::UnexpectedEof),
+            e => io::Error::new(io::ErrorKind::InvalidData, e),
+        };
+
+        // read the header a byte at a time, so that nothing past it is consumed
+        let mut header = Vec::with_capacity(Infinint::MAX_HEADER_LEN);
+        let mut byte = [0];
+        let (negative, len, header_len) = loop {
+            r.read_exact(&mut byte)?;
+            header.push(byte[0]);
+            match Infinint::decode_header(&header) {
+                Err(DecodeError::UnexpectedEnd) => (),
+                result => break result.map_err(into_io_error)?,
+            }
+        };
+
+        // a corrupted length can be huge, so let the buffer grow with the data that actually
+        // arrives rather than allocating it up front
+        let mut digits = Vec::new();
+        r.take(len as u64).read_to_end(&mut digits)?;
+        if digits.len() < len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        Infinint::from_encoded_digits(negative, digits, header_len).map_err(into_io_error)
+    }
+
+    fn encode_header(&self, bytes: &mut Vec<u8>) {
+        bytes.push(Infinint::ENCODING_VERSION);
+        bytes.push((self.negative && !self.is_zero()) as u8);
+
+        let mut len = self.digits_vec.len();
+        while len >= 0x80 {
+            bytes.push((len & 0x7F) as u8 | 0x80);
+            len >>= 7;
+        }
+        bytes.push(len as u8);
+    }
+
+    fn decode_header(bytes: &[u8]) -> Result<(bool, usize, usize), DecodeError> {
+        // returns the sign, the number of digit bytes and the length of the header; errors are
+        // reported as soon as the bytes so far show them, which `read_from` relies on
+        let (&version, rest) = bytes.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+        if version != Infinint::ENCODING_VERSION {
+            return Err(DecodeError::UnsupportedVersion(version));
+        }
+        let (&sign, rest) = rest.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+        let negative = match sign {
+            0 => false,
+            1 => true,
+            _ => return Err(DecodeError::InvalidSign(sign)),
+        };
+
+        let mut len: usize = 0;
+        let mut shift = 0;
+        for (i, byte) in rest.iter().enumerate() {
+            let bits = usize::from(byte & 0x7F);
+            if shift >= usize::MAX.count_ones() || bits << shift >> shift != bits {
+                return Err(DecodeError::InvalidLength);
+            }
+            len |= bits << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                if len == 0 {
+                    return Err(DecodeError::InvalidLength);
+                }
+                return Ok((negative, len, i + 3));
+            }
+        }
+
+        Err(DecodeError::UnexpectedEnd)
+    }
+
+    fn from_encoded_digits(
+        negative: bool,
+        mut digits_vec: Vec<u8>,
+        offset: usize,
+    ) -> Result<Infinint, DecodeError> {
+        if let Some(i) = digits_vec
+            .iter()
+            .position(|byte| decimal_digits(*byte).is_err())
+        {
+            return Err(DecodeError::InvalidDigit {
+                position: offset + i,
+            });
+        }
+
+        Infinint::trim_digits_vec(&mut digits_vec);
+        let negative = negative && digits_vec != [0];
+
+        Ok(Infinint {
+            negative,
+            digits_vec,
+        })
+    }
+
+    /// Constructs an Infinint from a sign and a slice of decimal digits, in little-endian order.
+    ///
+    /// An error is returned if the slice is empty or any digit is not between 0 and 9.
+    /// Most-significant zeros are trimmed, and a negative zero is normalized to positive zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::{Infinint, NybbleError};
+    /// let x = Infinint::from_digits(false, &[8, 9, 9, 1]).unwrap();
+    /// assert_eq!(x, Infinint::from(1998));
+    ///
+    /// let e = Infinint::from_digits(false, &[1, 10]).unwrap_err();
+    /// assert_eq!(e, NybbleError::InvalidDigit { position: 1, found: 10 });
+    /// ```
+    pub fn from_digits(negative: bool, digits: &[u8]) -> Result<Infinint, NybbleError> {
+        if digits.is_empty() {
+            return Err(NybbleError::Empty);
+        }
+        for (position, digit) in digits.iter().enumerate() {
+            decimal_digit_nybble(*digit).map_err(|e| e.at(position))?;
+        }
+
+        let digits_vec = Infinint::digits_vec_from_digits(digits);
+        let negative = negative && digits_vec != [0];
+
+        Ok(Infinint {
+            negative,
+            digits_vec,
+        })
+    }
+
+    fn cmp_digits(n_digits_vec: &[u8], m_digits_vec: &[u8]) -> cmp::Ordering {
+        // walk down from the most-significant byte, treating missing bytes as zero
+        let len = cmp::max(n_digits_vec.len(), m_digits_vec.len());
+
+        for i in (0..len).rev() {
+            let n_next_digits = *n_digits_vec.get(i).unwrap_or(&0);
+            let m_next_digits = *m_digits_vec.get(i).unwrap_or(&0);
+
+            let n_next_digits = decimal_digits(n_next_digits).unwrap();
+            let m_next_digits = decimal_digits(m_next_digits).unwrap();
+
+            if n_next_digits.1 < m_next_digits.1 {
+                return cmp::Ordering::Less;
+            } else if n_next_digits.1 > m_next_digits.1 {
+                return cmp::Ordering::Greater;
+            }
+
+            if n_next_digits.0 < m_next_digits.0 {
+                return cmp::Ordering::Less;
+            } else if n_next_digits.0 > m_next_digits.0 {
+                return cmp::Ordering::Greater;
+            }
+        }
+
+        cmp::Ordering::Equal
+    }
+
+    fn infinint_cmp(n: &Infinint, m: &Infinint) -> cmp::Ordering {
+        // zero is neither positive nor negative, so -0 and +0 compare equal
+        let n_negative = n.negative && !n.is_zero();
+        let m_negative = m.negative && !m.is_zero();
+
+        if n_negative && !m_negative {
+            cmp::Ordering::Less
+        } else if !n_negative && m_negative {
+            cmp::Ordering::Greater
+        } else if n_negative {
+            // a larger magnitude means a smaller value when both are negative
+            n.cmp_abs(m).reverse()
+        } else {
+            n.cmp_abs(m)
+        }
+    }
+
+    fn op_digits(
+        n_digits_vec: &[u8],
+        m_digits_vec: &[u8],
+        op: fn(u8, u8, u8) -> (u8, u8),
+    ) -> Vec<u8> {
+        let len = cmp::max(n_digits_vec.len(), m_digits_vec.len());
+        let mut carry = 0;
+        let mut result_digits_vec: Vec<u8> = Vec::with_capacity(len + 1);
+
+        for i in 0..len {
+            let n_next_digits = *n_digits_vec.get(i).unwrap_or(&0);
+            let m_next_digits = *m_digits_vec.get(i).unwrap_or(&0);
+
+            let n_digits = decimal_digits(n_next_digits).unwrap();
+            let m_digits = decimal_digits(m_next_digits).unwrap();
+
+            let (upper_result_digit, new_carry) = op(n_digits.0, m_digits.0, carry);
+            carry = new_carry;
+
+            let (lower_result_digit, new_carry) = op(n_digits.1, m_digits.1, carry);
+            carry = new_carry;
+
+            let result_digit = (upper_result_digit << 4) | lower_result_digit;
+            result_digits_vec.push(result_digit);
+        }
+
+        // possible because:
+        // - this does not apply to subtraction; we can guarantee carry == 0 at the end
+        // - if the carry is not 0 after the last run of addition, this means there is overflow
+        //     to a new digit; if there wasn't overflow, the second update of result_digit
+        //     would capture the carry
+        if carry > 0 {
+            result_digits_vec.push(carry << 4);
+        }
+
+        // subtraction can leave most-significant zeros behind, e.g. 100 - 99
+        Infinint::trim_digits_vec(&mut result_digits_vec);
+
+        result_digits_vec
+    }
+
+    fn infinint_add(
+        n: &Infinint,
+        m: &Infinint,
+        negate_n: bool,
+        negate_m: bool,
+        negate_result: bool,
+    ) -> Infinint {
+        let n_negative = if !negate_n {
+            n.negative
+        } else {
+            !n.negative
+        };
+        let m_negative = if !negate_m {
+            m.negative
+        } else {
+            !m.negative
+        };
+
+        if !n_negative && m_negative {
+            return Infinint::infinint_subtract(n, m, negate_n, !negate_m, negate_result);
+        } else if n_negative && !m_negative {
+            return Infinint::infinint_subtract(m, n, negate_m, !negate_n, negate_result);
+        } // otherwise, negative can be determined later
+
+        let result_digits_vec =
+            Infinint::op_digits(&n.digits_vec, &m.digits_vec, decimal_add_with_carry);
+
+        let result_negative = if !negate_result {
+            n_negative
+        } else {
+            !n_negative
+        };
+
+        Infinint {
+            negative: result_negative,
+            digits_vec: result_digits_vec,
+        }
+    }
+
+    fn infinint_subtract(
+        n: &Infinint,
+        m: &Infinint,
+        negate_n: bool,
+        negate_m: bool,
+        negate_result: bool,
+    ) -> Infinint {
+        let n_negative = if !negate_n {
+            n.negative
+        } else {
+            !n.negative
+        };
+        let m_negative = if !negate_m {
+            m.negative
+        } else {
+            !m.negative
+        };
+
+        if !n_negative && m_negative {
+            return Infinint::infinint_add(n, m, negate_n, !negate_m, negate_result);
+        } else if n_negative && !m_negative {
+            return Infinint::infinint_add(n, m, !negate_n, negate_m, !negate_result);
+        } else if n_negative && m_negative {
+            return Infinint::infinint_subtract(m, n, !negate_m, !negate_n, negate_result);
+        }
+
+        // both operands are effectively positive here, so only the magnitudes matter
+        match n.cmp_abs(m) {
+            cmp::Ordering::Less => {
+                return Infinint::infinint_subtract(m, n, negate_m, negate_n, !negate_result);
+            }
+            cmp::Ordering::Equal => return Infinint::from(0),
+            cmp::Ordering::Greater => (),
+        }
+
+        let result_digits_vec =
+            Infinint::op_digits(&n.digits_vec, &m.digits_vec, decimal_subtract_with_carry);
+
+        let result_negative = negate_result;
+
+        Infinint {
+            negative: result_negative,
+            digits_vec: result_digits_vec,
+        }
+    }
+
+    fn infinint_divmod(n: &Infinint, m: &Infinint) -> (Infinint, Infinint) {
+        assert!(!m.is_zero(), "attempt to divide by zero");
+
+        match m.magnitude_u128().map(u32::try_from) {
+            Some(Ok(divisor)) => {
+                let (mut quotient, remainder) = n.divmod_small(divisor);
+                quotient.negative = n.negative != m.negative && !quotient.is_zero();
+                let remainder = Infinint {
+                    negative: n.negative && remainder != 0,
+                    digits_vec: Infinint::digits_vec_from_int(u128::from(remainder)),
+                };
+                (quotient, remainder)
+            }
+            _ => Infinint::long_divmod(n, m),
+        }
+    }
+
+    fn long_divmod(n: &Infinint, m: &Infinint) -> (Infinint, Infinint) {
+        // long division on the magnitudes, most-significant digit first; the remainder is kept
+        // packed and updated in place so that each step avoids allocating
+        let n_digits = n.digits();
+        let mut quotient_digits = vec![0; n_digits.len()];
+        let mut remainder_digits_vec: Vec<u8> = Vec::with_capacity(m.digits_vec.len() + 1);
+        remainder_digits_vec.push(0);
+
+        for (i, digit) in n_digits.iter().enumerate().rev() {
+            Infinint::shift_in_digit(&mut remainder_digits_vec, *digit);
+
+            while Infinint::cmp_digits(&remainder_digits_vec, &m.digits_vec) != cmp::Ordering::Less
+            {
+                Infinint::sub_digits_in_place(&mut remainder_digits_vec, &m.digits_vec);
+                quotient_digits[i] += 1;
+            }
+        }
+
+        // like the primitive types, the quotient is truncated toward zero and the remainder takes
+        // the sign of the dividend
+        let quotient_digits_vec = Infinint::digits_vec_from_digits(&quotient_digits);
+        let quotient_negative = n.negative != m.negative && quotient_digits_vec != [0];
+        let remainder_negative = n.negative && remainder_digits_vec != [0];
+
+        let quotient = Infinint {
+            negative: quotient_negative,
+            digits_vec: quotient_digits_vec,
+        };
+        let remainder = Infinint {
+            negative: remainder_negative,
+            digits_vec: remainder_digits_vec,
+        };
+        (quotient, remainder)
+    }
+
+    fn shift_in_digit(digits_vec: &mut Vec<u8>, digit: u8) {
+        // multiply by ten and add `digit`: every digit moves up one nybble, so each byte takes the
+        // next digit down from the byte below it
+        let mut carry = digit;
+        for byte in digits_vec.iter_mut() {
+            let shifted = (carry << 4) | ((0xF0 & *byte) >> 4);
+            carry = 0x0F & *byte;
+            *byte = shifted;
+        }
+        if carry > 0 {
+            digits_vec.push(carry << 4);
+        }
+        Infinint::trim_digits_vec(digits_vec);
+    }
+
+    fn add_digits_in_place(n_digits_vec: &mut Vec<u8>, m_digits_vec: &[u8]) {
+        // only grows `n` when `m` is longer or the carry runs out of the most-significant byte
+        if n_digits_vec.len() < m_digits_vec.len() {
+            n_digits_vec.resize(m_digits_vec.len(), 0);
+        }
+        let mut carry = 0;
+
+        for (i, byte) in n_digits_vec.iter_mut().enumerate() {
+            if i >= m_digits_vec.len() && carry == 0 {
+                break;
+            }
+            let n_digits = decimal_digits(*byte).unwrap();
+            let m_digits = decimal_digits(*m_digits_vec.get(i).unwrap_or(&0)).unwrap();
+
+            let (upper_result_digit, new_carry) =
+                decimal_add_with_carry(n_digits.0, m_digits.0, carry);
+            let (lower_result_digit, new_carry) =
+                decimal_add_with_carry(n_digits.1, m_digits.1, new_carry);
+            carry = new_carry;
+
+            *byte = (upper_result_digit << 4) | lower_result_digit;
+        }
+
+        if carry > 0 {
+            n_digits_vec.push(carry << 4);
+        }
+    }
+
+    fn sub_digits_in_place(n_digits_vec: &mut Vec<u8>, m_digits_vec: &[u8]) {
+        // requires n >= m, so there is no borrow out of the most-significant byte
+        let mut carry = 0;
+
+        for (i, byte) in n_digits_vec.iter_mut().enumerate() {
+            let n_digits = decimal_digits(*byte).unwrap();
+            let m_digits = decimal_digits(*m_digits_vec.get(i).unwrap_or(&0)).unwrap();
+
+            let (upper_result_digit, new_carry) =
+                decimal_subtract_with_carry(n_digits.0, m_digits.0, carry);
+            let (lower_result_digit, new_carry) =
+                decimal_subtract_with_carry(n_digits.1, m_digits.1, new_carry);
+            carry = new_carry;
+
+            *byte = (upper_result_digit << 4) | lower_result_digit;
+        }
+
+        Infinint::trim_digits_vec(n_digits_vec);
+    }
+
+    fn mul_digits(n_digits: &[u8], m_digits: &[u8]) -> Vec<u8> {
+        let result_digits = Infinint::mul_unpacked(n_digits, m_digits, KARATSUBA_THRESHOLD);
+        Infinint::digits_vec_from_digits(&result_digits)
+    }
+
+    fn mul_unpacked(n_digits: &[u8], m_digits: &[u8], threshold: usize) -> Vec<u8> {
+        // multiplies little-endian digit slices into exactly n + m digits, which may include
+        // most-significant zeros; Karatsuba is used while both operands are at least `threshold`
+        // digits long
+        if n_digits.len() < m_digits.len() {
+            return Infinint::mul_unpacked(m_digits, n_digits, threshold);
+        }
+        // below four digits, the sums of the halves are no shorter than n and the recursion
+        // would never end
+        if m_digits.len() < cmp::max(threshold, 4) {
+            return Infinint::mul_schoolbook(n_digits, m_digits);
+        }
+
+        let half = n_digits.len() / 2;
+        let (n_low, n_high) = n_digits.split_at(half);
+        let mut result = vec![0; n_digits.len() + m_digits.len()];
+
+        if m_digits.len() <= half {
+            // too lopsided to split both operands, so multiply m by each half of n
+            let low = Infinint::mul_unpacked(n_low, m_digits, threshold);
+            let high = Infinint::mul_unpacked(n_high, m_digits, threshold);
+            Infinint::add_unpacked_at(&mut result, &low, 0);
+            Infinint::add_unpacked_at(&mut result, &high, half);
+            return result;
+        }
+
+        // with n = n_high * 10^half + n_low, and likewise m:
+        // n * m = z2 * 10^(2 * half) + z1 * 10^half + z0, where
+        // z1 = (n_high + n_low) * (m_high + m_low) - z2 - z0
+        let (m_low, m_high) = m_digits.split_at(half);
+        let z0 = Infinint::mul_unpacked(n_low, m_low, threshold);
+        let z2 = Infinint::mul_unpacked(n_high, m_high, threshold);
+        let mut z1 = Infinint::mul_unpacked(
+            &Infinint::add_unpacked(n_low, n_high),
+            &Infinint::add_unpacked(m_low, m_high),
+            threshold,
+        );
+        Infinint::sub_unpacked_in_place(&mut z1, &z0);
+        Infinint::sub_unpacked_in_place(&mut z1, &z2);
+
+        Infinint::add_unpacked_at(&mut result, &z0, 0);
+        Infinint::add_unpacked_at(&mut result, &z1, half);
+        Infinint::add_unpacked_at(&mut result, &z2, 2 * half);
+        result
+    }
+
+    fn add_unpacked(n_digits: &[u8], m_digits: &[u8]) -> Vec<u8> {
+        let mut result = vec![0; cmp::max(n_digits.len(), m_digits.len()) + 1];
+        Infinint::add_unpacked_at(&mut result, n_digits, 0);
+        Infinint::add_unpacked_at(&mut result, m_digits, 0);
+        if result.last() == Some(&0) {
+            result.pop();
+        }
+        result
+    }
+
+    fn add_unpacked_at(digits: &mut [u8], addend: &[u8], offset: usize) {
+        // adds `addend * 10^offset` into `digits`, which must be long enough for the sum; any
+        // most-significant zeros of `addend` past its end are skipped
+        let mut carry = 0;
+        let mut i = offset;
+        for addend_digit in addend.iter() {
+            if i == digits.len() {
+                debug_assert_eq!(*addend_digit, 0);
+                continue;
+            }
+            let (digit, new_carry) = decimal_add_with_carry(digits[i], *addend_digit, carry);
+            digits[i] = digit;
+            carry = new_carry;
+            i += 1;
+        }
+        while carry > 0 {
+            let (digit, new_carry) = decimal_add_with_carry(digits[i], 0, carry);
+            digits[i] = digit;
+            carry = new_carry;
+            i += 1;
+        }
+    }
+
+    fn sub_unpacked_in_place(digits: &mut [u8], subtrahend: &[u8]) {
+        // requires digits >= subtrahend, so there is no borrow out of the most-significant digit
+        let mut carry = 0;
+        for (i, digit) in digits.iter_mut().enumerate() {
+            if i >= subtrahend.len() && carry == 0 {
+                break;
+            }
+            let subtrahend_digit = *subtrahend.get(i).unwrap_or(&0);
+            let (result, new_carry) = decimal_subtract_with_carry(*digit, subtrahend_digit, carry);
+            *digit = result;
+            carry = new_carry;
+        }
+    }
+
+    fn mul_schoolbook(n_digits: &[u8], m_digits: &[u8]) -> Vec<u8> {
+        // accumulate each column before propagating the carries; a column can hold up to
+        // 81 * min(n, m), so u64 is needed past roughly 53M digits
+        let mut columns: Vec<u64> = vec![0; n_digits.len() + m_digits.len()];
+        for (i, n_digit) in n_digits.iter().enumerate() {
+            for (j, m_digit) in m_digits.iter().enumerate() {
+                columns[i + j] += u64::from(*n_digit) * u64::from(*m_digit);
+            }
+        }
+
+        let mut carry = 0;
+        columns
+            .into_iter()
+            .map(|column| {
+                let column = column + carry;
+                carry = column / 10;
+                (column % 10) as u8
+            })
+            .collect()
+    }
+
+    fn infinint_multiply(n: &Infinint, m: &Infinint) -> Infinint {
+        let result_digits_vec = Infinint::mul_digits(&n.digits(), &m.digits());
+        let result_negative = n.negative != m.negative && result_digits_vec != [0];
+
+        Infinint {
+            negative: result_negative,
+            digits_vec: result_digits_vec,
+        }
+    }
+}
+
+/// The sign of an Infinint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sign {
+    /// Less than zero.
+    Negative,
+    /// Exactly zero.
+    Zero,
+    /// Greater than zero.
+    Positive,
+}
+
+/// Displays an Infinint with custom digit grouping, as returned by
+/// [`Infinint::format_grouped`](struct.Infinint.html#method.format_grouped).
+#[derive(Debug, Clone, Copy)]
+pub struct GroupedDisplay<'a> {
+    value: &'a Infinint,
+    separator: char,
+    first_group_size: usize,
+    group_size: usize,
+    min_digits: usize,
+}
+
+impl<'a> GroupedDisplay<'a> {
+    /// Sets the size of the least-significant group separately from the others, as in the 3-2-2
+    /// grouping used in India. A size of zero disables grouping.
+    pub fn first_group_size(mut self, first_group_size: usize) -> GroupedDisplay<'a> {
+        self.first_group_size = first_group_size;
+        self
+    }
+
+    /// Only groups numbers with at least `min_digits` digits, leaving shorter ones as plain
+    /// digits. Zeros added by padding do not count toward this.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let short = Infinint::from(1000);
+    /// assert_eq!(short.format_grouped(',', 3).min_digits(5).to_string(), "1000");
+    ///
+    /// let long = Infinint::from(10_000);
+    /// assert_eq!(long.format_grouped(',', 3).min_digits(5).to_string(), "10,000");
+    /// ```
+    pub fn min_digits(mut self, min_digits: usize) -> GroupedDisplay<'a> {
+        self.min_digits = min_digits;
+        self
+    }
+}
+
+impl fmt::Display for GroupedDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let min_num_digits = f.precision().unwrap_or(0);
+        if self.value.num_digits() < self.min_digits {
+            self.value
+                .fmt_grouped(f, self.separator, 0, 0, min_num_digits)
+        } else {
+            self.value.fmt_grouped(
+                f,
+                self.separator,
+                self.first_group_size,
+                self.group_size,
+                min_num_digits,
+            )
+        }
+    }
+}
+
+/// An error which can be returned when parsing an Infinint.
+///
+/// # Examples
+/// ```rust
+/// # use infinint::{Infinint, ParseInfinintError};
+/// let e = "12x4".parse::<Infinint>().unwrap_err();
+/// assert_eq!(e, ParseInfinintError::InvalidDigit { position: 2, found: 'x' });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseInfinintError {
+    /// The string contained no digits.
+    Empty,
+    /// The string contained a character which is not a digit in the radix being parsed.
+    InvalidDigit {
+        /// The index of the offending character in the string.
+        position: usize,
+        /// The offending character.
+        found: char,
+    },
+    /// The radix passed to [`Infinint::from_str_radix`](struct.Infinint.html#method.from_str_radix)
+    /// was not between 2 and 36.
+    InvalidRadix(u32),
+    /// The string was longer than the limit passed to
+    /// [`Infinint::from_str_max`](struct.Infinint.html#method.from_str_max).
+    TooLong {
+        /// The maximum number of digits allowed.
+        max_digits: usize,
+    },
+}
+
+impl fmt::Display for ParseInfinintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInfinintError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseInfinintError::InvalidDigit { position, found } => {
+                write!(f, "invalid digit {:?} at position {}", found, position)
+            }
+            ParseInfinintError::InvalidRadix(radix) => {
+                write!(f, "invalid radix {}, must be between 2 and 36", radix)
+            }
+            ParseInfinintError::TooLong { max_digits } => {
+                write!(f, "number has more than {} digits", max_digits)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ParseInfinintError {}
+
+/// An error which can be returned when constructing an Infinint from digits or packed digit bytes,
+/// with [`Infinint::from_digits`](struct.Infinint.html#method.from_digits) or
+/// [`Infinint::from_bytes`](struct.Infinint.html#method.from_bytes).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NybbleError {
+    /// No digits were given.
+    Empty,
+    /// A digit, or a nybble of a packed byte, was not between 0 and 9.
+    InvalidDigit {
+        /// The index of the offending digit or byte in the input.
+        position: usize,
+        /// The offending digit or nybble.
+        found: u8,
+    },
+}
+
+impl NybbleError {
+    fn at(self, position: usize) -> NybbleError {
+        match self {
+            NybbleError::InvalidDigit { found, .. } => {
+                NybbleError::InvalidDigit { position, found }
+            }
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for NybbleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NybbleError::Empty => write!(f, "no digits"),
+            NybbleError::InvalidDigit { position, found } => {
+                write!(f, "invalid digit {} at position {}", found, position)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for NybbleError {}
+
+/// An error which can be returned when decoding an Infinint with
+/// [`Infinint::decode`](struct.Infinint.html#method.decode).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended before the header or the digits were complete.
+    UnexpectedEnd,
+    /// The version byte is not one this crate can read.
+    UnsupportedVersion(u8),
+    /// The sign byte is neither 0 nor 1.
+    InvalidSign(u8),
+    /// The length is zero or too large to represent.
+    InvalidLength,
+    /// The input continues past the number of digit bytes given in the header.
+    TrailingBytes,
+    /// A digit byte contains a nybble which is not a decimal digit.
+    InvalidDigit {
+        /// The index of the offending byte in the input.
+        position: usize,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of encoded integer"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported encoding version {}", version)
+            }
+            DecodeError::InvalidSign(sign) => write!(f, "invalid sign byte {}", sign),
+            DecodeError::InvalidLength => write!(f, "invalid digit length"),
+            DecodeError::TrailingBytes => write!(f, "trailing bytes after encoded integer"),
+            DecodeError::InvalidDigit { position } => {
+                write!(f, "invalid digit byte at position {}", position)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for DecodeError {}
+
+/// The error returned when an Infinint is out of range of the primitive integer it is being
+/// converted to with `TryFrom`.
+///
+/// # Examples
+/// ```rust
+/// # use infinint::Infinint;
+/// use std::convert::TryFrom;
+///
+/// assert_eq!(u128::try_from(&Infinint::from(1998)), Ok(1998));
+/// assert!(u128::try_from(&Infinint::from(-1998)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromInfinintError(());
+
+impl fmt::Display for TryFromInfinintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for TryFromInfinintError {}
+
+impl Default for Infinint {
+    fn default() -> Infinint {
+        Infinint::new()
+    }
+}
+
+impl fmt::Debug for Infinint {
+    /// Lists each packed byte in binary alongside the two digits it holds. The alternate flag
+    /// (`{:#?}`) prints a one-line summary instead: the sign, the number of digits and a hex dump
+    /// of the bytes, in which each byte reads as its two digits with the less-significant first.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "Infinint {{ negative: {}, num_digits: {}, digits_vec: [",
+                self.negative,
+                self.num_digits()
+            )?;
+            for (i, byte) in self.digits_vec.iter().enumerate() {
+                let separator = if i > 0 { " " } else { "" };
+                write!(f, "{}{:02x}", separator, byte)?;
+            }
+            return write!(f, "] }}");
+        }
+
+        write!(f, "\nnegative: {}\n", self.negative)?;
+        writeln!(f, "digits: [")?;
+        self.digits_vec.iter()
+            .cloned()
+            .map(|d| (d, decimal_digits(d).unwrap()))
+            .try_for_each(|(d, (lo, hi))| writeln!(f,
+                    "\t{:04b}_{:04b} -> ({}, {})",
+                    (0xF0 & d) >> 4,
+                    0xF & d,
+                    lo,
+                    hi))?;
+        write!(f, "]")
+    }
+}
+
+impl fmt::Display for Infinint {
+    /// Displays the Infinint in decimal, with commas between groups of three digits. The alternate
+    /// flag (`{:#}`) leaves out the commas.
+    ///
+    /// Width, fill and alignment behave as for the primitive integers. Zero-padding (`{:08}`) adds
+    /// zeros before grouping, so they are grouped too, and the output is widened by one character
+    /// rather than start with a comma. With the alternate flag (`{:#08}`) the zeros are plain.
+    ///
+    /// Unlike the primitive integers, which ignore it, a precision (`{:.3}`) is the number of
+    /// significant digits to show. A value with more digits than that is abbreviated to a mantissa
+    /// of that many digits, truncated toward zero, times a power of ten, as in `1.23×10^29`. A
+    /// value which fits is shown in full, as if there were no precision. A precision of zero
+    /// counts as one. The width is then filled around the result and the sign as usual. For a
+    /// minimum number of digits instead, use [`format_grouped`](#method.format_grouped).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(precision) = f.precision() {
+            let significant = cmp::max(precision, 1);
+            if self.num_digits() > significant {
+                return self.fmt_abbreviated(f, significant);
+            }
+        }
+
+        let group_size = if !f.alternate() { 3 } else { 0 };
+        self.fmt_grouped(f, ',', group_size, group_size, 0)
+    }
+}
+
+impl str::FromStr for Infinint {
+    type Err = ParseInfinintError;
+
+    /// Parses a string with an optional leading `+` or `-` sign. After the sign, a `0x`, `0o`
+    /// or `0b` prefix selects hexadecimal, octal or binary digits, as for
+    /// [`Infinint::from_str_radix`](struct.Infinint.html#method.from_str_radix); otherwise the
+    /// digits are decimal. Error positions count the sign and the prefix.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// assert_eq!("0xff".parse::<Infinint>(), Ok(Infinint::from(255)));
+    /// assert_eq!("-0b101".parse::<Infinint>(), Ok(Infinint::from(-5)));
+    /// assert_eq!("255".parse::<Infinint>(), Ok(Infinint::from(255)));
+    /// ```
+    fn from_str(src: &str) -> Result<Infinint, ParseInfinintError> {
+        let (negative, offset) = Infinint::parse_sign(src);
+        match Infinint::parse_radix_prefix(&src[offset..]) {
+            Some(radix) => Infinint::parse_radix_digits(src, radix, negative, offset + 2),
+            None => Infinint::from_str_grouped(src, &[]),
+        }
+    }
+}
+
+impl TryFrom<&str> for Infinint {
+    type Error = ParseInfinintError;
+
+    fn try_from(src: &str) -> Result<Infinint, ParseInfinintError> {
+        src.parse()
+    }
+}
+
+impl TryFrom<String> for Infinint {
+    type Error = ParseInfinintError;
+
+    fn try_from(src: String) -> Result<Infinint, ParseInfinintError> {
+        src.parse()
+    }
+}
+
+impl TryFrom<&[u8]> for Infinint {
+    type Error = NybbleError;
+
+    /// Reads nybble-packed digit bytes, as returned by
+    /// [`Infinint::as_bytes`](struct.Infinint.html#method.as_bytes), as a non-negative value. This
+    /// is [`Infinint::from_bytes`](struct.Infinint.html#method.from_bytes) with a positive sign.
+    fn try_from(bytes: &[u8]) -> Result<Infinint, NybbleError> {
+        Infinint::from_bytes(false, bytes)
+    }
+}
+
+impl TryFrom<char> for Infinint {
+    type Error = ParseInfinintError;
+
+    /// Converts a single decimal digit, `'0'` to `'9'`, into an Infinint.
+    fn try_from(c: char) -> Result<Infinint, ParseInfinintError> {
+        match c.to_digit(10) {
+            Some(d) => Ok(Infinint::from(d)),
+            None => Err(ParseInfinintError::InvalidDigit {
+                position: 0,
+                found: c,
+            }),
+        }
+    }
+}
+
+impl From<u128> for Infinint {
+    fn from(n: u128) -> Infinint {
+        let digits_vec = Infinint::digits_vec_from_int(n);
+
+        Infinint {
+            negative: false,
+            digits_vec,
+        }
+    }
+}
+
+impl From<i128> for Infinint {
+    fn from(n: i128) -> Infinint {
+        let negative = n < 0;
+        let digits_vec = Infinint::digits_vec_from_int(n.unsigned_abs());
+
+        Infinint {
+            negative,
+            digits_vec,
+        }
+    }
+}
+
+// every narrower primitive widens losslessly into the 128-bit type of the same signedness; `as`
+// is used because `From` is not implemented from usize and isize, though they are never wider
+macro_rules! impl_from_primitive {
+    ($wide:ty: $($narrow:ty),*) => {
+        $(
+            impl From<$narrow> for Infinint {
+                fn from(n: $narrow) -> Infinint {
+                    Infinint::from(n as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(u128: usize, u64, u32, u16, u8);
+impl_from_primitive!(i128: isize, i64, i32, i16, i8);
+
+impl TryFrom<&Infinint> for u128 {
+    type Error = TryFromInfinintError;
+
+    fn try_from(n: &Infinint) -> Result<u128, TryFromInfinintError> {
+        match n.magnitude_u128() {
+            Some(magnitude) if !n.negative || magnitude == 0 => Ok(magnitude),
+            _ => Err(TryFromInfinintError(())),
+        }
+    }
+}
+
+impl TryFrom<&Infinint> for i128 {
+    type Error = TryFromInfinintError;
+
+    fn try_from(n: &Infinint) -> Result<i128, TryFromInfinintError> {
+        let magnitude = n.magnitude_u128().ok_or(TryFromInfinintError(()))?;
+        if !n.negative {
+            i128::try_from(magnitude).map_err(|_| TryFromInfinintError(()))
+        } else if magnitude == i128::MIN.unsigned_abs() {
+            Ok(i128::MIN)
+        } else {
+            i128::try_from(magnitude)
+                .map(|magnitude| -magnitude)
+                .map_err(|_| TryFromInfinintError(()))
+        }
+    }
+}
+
+impl iter::FromIterator<u8> for Infinint {
+    /// Collects decimal digits in little-endian order, the ones digit first, into a non-negative
+    /// Infinint. An empty iterator gives zero.
+    ///
+    /// # Panics
+    /// Panics if any digit is not between 0 and 9. Use
+    /// [`from_digits()`](struct.Infinint.html#method.from_digits) to handle that as an error.
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Infinint {
+        let mut digits_vec = Vec::new();
+        for (i, digit) in iter.into_iter().enumerate() {
+            assert!(digit < 10, "digit out of range: {}", digit);
+            if i % 2 == 0 {
+                digits_vec.push(digit << 4);
+            } else {
+                *digits_vec.last_mut().unwrap() |= digit;
+            }
+        }
+        Infinint::trim_digits_vec(&mut digits_vec);
+
+        Infinint {
+            negative: false,
+            digits_vec,
+        }
+    }
+}
+
+impl cmp::Ord for Infinint {
+    fn cmp(&self, other: &Infinint) -> cmp::Ordering {
+        Infinint::infinint_cmp(self, other)
+    }
+}
+
+impl cmp::PartialOrd for Infinint {
+    fn partial_cmp(&self, other: &Infinint) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Eq for Infinint {}
+
+impl cmp::PartialEq for Infinint {
+    fn eq(&self, other: &Infinint) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl cmp::PartialEq<i128> for Infinint {
+    fn eq(&self, other: &i128) -> bool {
+        self.cmp(&Infinint::from(*other)) == cmp::Ordering::Equal
+    }
+}
+
+impl cmp::PartialEq<Infinint> for i128 {
+    fn eq(&self, other: &Infinint) -> bool {
+        Infinint::from(*self).cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl cmp::PartialOrd<i128> for Infinint {
+    fn partial_cmp(&self, other: &i128) -> Option<cmp::Ordering> {
+        Some(self.cmp(&Infinint::from(*other)))
+    }
+}
+
+impl cmp::PartialOrd<Infinint> for i128 {
+    fn partial_cmp(&self, other: &Infinint) -> Option<cmp::Ordering> {
+        Some(Infinint::from(*self).cmp(other))
+    }
+}
+
+impl ops::Neg for &Infinint {
+    type Output = Infinint;
+
+    fn neg(self) -> Infinint {
+        // keep zero non-negative, as the owned negation does
+        let new_negative = !self.negative && !self.is_zero();
+        Infinint {
+            negative: new_negative,
+            digits_vec: self.digits_vec.to_vec(),
+        }
+    }
+}
+
+impl ops::Neg for Infinint {
+    type Output = Infinint;
+
+    fn neg(mut self) -> Infinint {
+        // flip the sign in place, keeping zero non-negative
+        self.negative = !self.negative && !self.is_zero();
+        self
+    }
+}
+
+impl ops::Add<&Infinint> for &Infinint {
+    type Output = Infinint;
+    fn add(self, other: &Infinint) -> Infinint {
+        Infinint::infinint_add(self, other, false, false, false)
+    }
+}
+
+impl ops::Add<Infinint> for Infinint {
+    type Output = Infinint;
+
+    fn add(self, other: Infinint) -> Infinint {
+        &self + &other
+    }
+}
+
+impl ops::Sub<&Infinint> for &Infinint {
+    type Output = Infinint;
+
+    fn sub(self, other: &Infinint) -> Infinint {
+        Infinint::infinint_subtract(self, other, false, false, false)
+    }
+}
+
+impl ops::Sub<Infinint> for Infinint {
+    type Output = Infinint;
+
+    fn sub(self, other: Infinint) -> Infinint {
+        &self - &other
+    }
+}
+
+impl ops::Add<i128> for &Infinint {
+    type Output = Infinint;
+
+    fn add(self, other: i128) -> Infinint {
+        self + &Infinint::from(other)
+    }
+}
+
+impl ops::Add<i128> for Infinint {
+    type Output = Infinint;
+
+    fn add(self, other: i128) -> Infinint {
+        &self + &Infinint::from(other)
+    }
+}
+
+impl ops::Add<&Infinint> for i128 {
+    type Output = Infinint;
+
+    fn add(self, other: &Infinint) -> Infinint {
+        &Infinint::from(self) + other
+    }
+}
+
+impl ops::Add<Infinint> for i128 {
+    type Output = Infinint;
+
+    fn add(self, other: Infinint) -> Infinint {
+        &Infinint::from(self) + &other
+    }
+}
+
+impl ops::Sub<i128> for &Infinint {
+    type Output = Infinint;
+
+    fn sub(self, other: i128) -> Infinint {
+        self - &Infinint::from(other)
+    }
+}
+
+impl ops::Sub<i128> for Infinint {
+    type Output = Infinint;
+
+    fn sub(self, other: i128) -> Infinint {
+        &self - &Infinint::from(other)
+    }
+}
+
+impl ops::Sub<&Infinint> for i128 {
+    type Output = Infinint;
+
+    fn sub(self, other: &Infinint) -> Infinint {
+        &Infinint::from(self) - other
+    }
+}
+
+impl ops::Sub<Infinint> for i128 {
+    type Output = Infinint;
+
+    fn sub(self, other: Infinint) -> Infinint {
+        &Infinint::from(self) - &other
+    }
+}
+
+impl ops::Mul<&Infinint> for &Infinint {
+    type Output = Infinint;
+
+    fn mul(self, other: &Infinint) -> Infinint {
+        Infinint::infinint_multiply(self, other)
+    }
+}
+
+impl ops::Mul<Infinint> for Infinint {
+    type Output = Infinint;
+
+    fn mul(self, other: Infinint) -> Infinint {
+        &self * &other
+    }
+}
+
+impl ops::Div<&Infinint> for &Infinint {
+    type Output = Infinint;
+
+    fn div(self, other: &Infinint) -> Infinint {
+        Infinint::infinint_divmod(self, other).0
+    }
+}
+
+impl ops::Div<Infinint> for Infinint {
+    type Output = Infinint;
+
+    fn div(self, other: Infinint) -> Infinint {
+        &self / &other
+    }
+}
+
+impl ops::Rem<&Infinint> for &Infinint {
+    type Output = Infinint;
+
+    fn rem(self, other: &Infinint) -> Infinint {
+        Infinint::infinint_divmod(self, other).1
+    }
+}
+
+impl ops::Rem<Infinint> for Infinint {
+    type Output = Infinint;
+
+    fn rem(self, other: Infinint) -> Infinint {
+        &self % &other
+    }
+}
+
+impl ops::AddAssign<&Infinint> for Infinint {
+    /// Adds into the existing digits where possible, so a large accumulator is only reallocated
+    /// when it gains a byte.
+    fn add_assign(&mut self, other: &Infinint) {
+        if self.negative == other.negative || other.is_zero() {
+            Infinint::add_digits_in_place(&mut self.digits_vec, &other.digits_vec);
+        } else if self.cmp_abs(other) != cmp::Ordering::Less {
+            Infinint::sub_digits_in_place(&mut self.digits_vec, &other.digits_vec);
+            self.negative = self.negative && !self.is_zero();
+        } else {
+            *self = &*self + other;
+        }
+    }
+}
+
+impl ops::AddAssign<Infinint> for Infinint {
+    fn add_assign(&mut self, other: Infinint) {
+        *self += &other;
+    }
+}
+
+impl ops::AddAssign<i128> for Infinint {
+    fn add_assign(&mut self, other: i128) {
+        *self += &Infinint::from(other);
+    }
+}
+
+impl ops::SubAssign<i128> for Infinint {
+    fn sub_assign(&mut self, other: i128) {
+        // negating the Infinint rather than the i128 keeps i128::MIN from overflowing
+        *self += &-Infinint::from(other);
+    }
+}
+
+impl ops::MulAssign<&Infinint> for Infinint {
+    fn mul_assign(&mut self, other: &Infinint) {
+        *self = Infinint::infinint_multiply(self, other);
+    }
+}
+
+impl ops::MulAssign<i128> for Infinint {
+    fn mul_assign(&mut self, other: i128) {
+        *self *= &Infinint::from(other);
+    }
+}
+
+impl ops::DivAssign<&Infinint> for Infinint {
+    fn div_assign(&mut self, other: &Infinint) {
+        *self = Infinint::infinint_divmod(self, other).0;
+    }
+}
+
+impl ops::RemAssign<&Infinint> for Infinint {
+    fn rem_assign(&mut self, other: &Infinint) {
+        *self = Infinint::infinint_divmod(self, other).1;
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Infinint {
+    fn zero() -> Infinint {
+        Infinint::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        Infinint::is_zero(self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::One for Infinint {
+    fn one() -> Infinint {
+        Infinint::one()
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&Infinint> for num_bigint::BigInt {
+    fn from(n: &Infinint) -> num_bigint::BigInt {
+        let sign = match n.sign() {
+            Sign::Negative => num_bigint::Sign::Minus,
+            Sign::Zero => num_bigint::Sign::NoSign,
+            Sign::Positive => num_bigint::Sign::Plus,
+        };
+        // both sides can work with little-endian decimal digits, so no string is needed
+        num_bigint::BigInt::from_radix_le(sign, &n.digits(), 10).unwrap()
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&num_bigint::BigInt> for Infinint {
+    fn from(n: &num_bigint::BigInt) -> Infinint {
+        let (sign, digits) = n.to_radix_le(10);
+        Infinint {
+            negative: sign == num_bigint::Sign::Minus,
+            digits_vec: Infinint::digits_vec_from_digits(&digits),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Infinint {
+    /// Returns a uniformly random positive Infinint with exactly `num_digits` decimal digits.
+    ///
+    /// # Panics
+    /// Panics if `num_digits` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use infinint::Infinint;
+    /// let x = Infinint::random_with_digits(&mut rand::thread_rng(), 50);
+    /// assert_eq!(x.num_digits(), 50);
+    /// ```
+    pub fn random_with_digits<R: rand::Rng>(rng: &mut R, num_digits: usize) -> Infinint {
+        assert!(num_digits > 0, "cannot generate a number with no digits");
+
+        // every digit is uniform, except the most-significant one, which cannot be zero
+        let mut digits: Vec<u8> = (1..num_digits).map(|_| rng.gen_range(0..10)).collect();
+        digits.push(rng.gen_range(1..10));
+
+        Infinint {
+            negative: false,
+            digits_vec: Infinint::digits_vec_from_digits(&digits),
+        }
+    }
+}
+
+/// Low-level operations on the decimal digits packed two to a byte inside an [`Infinint`].
+///
+/// Each byte holds two digits, one per nybble; the upper nybble holds the less-significant digit.
+/// See the [`Infinint`] documentation for the full layout.
+pub mod nybble {
+    use super::NybbleError;
+
+    /// Unpacks a byte into its two decimal digits, the upper nybble first. An error is returned
+    /// if either nybble is greater than 9.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use infinint::nybble::decimal_digits;
+    ///
+    /// assert_eq!(decimal_digits(0x89), Ok((8, 9)));
+    /// assert!(decimal_digits(0x8A).is_err());
+    /// ```
+    pub fn decimal_digits(n: u8) -> Result<(u8, u8), NybbleError> {
+        let high = decimal_digit_high(n)?;
+        let low = decimal_digit_low(n)?;
+        Ok((high, low))
+    }
+
+    pub(crate) fn decimal_digit_high(n: u8) -> Result<u8, NybbleError> {
+        decimal_digit_nybble((0xF0 & n) >> 4)
+    }
+
+    pub(crate) fn decimal_digit_low(n: u8) -> Result<u8, NybbleError> {
+        decimal_digit_nybble(0x0F & n)
+    }
+
+    pub(crate) fn decimal_digit_nybble(n: u8) -> Result<u8, NybbleError> {
+        if n < 10 {
+            Ok(n)
+        } else {
+            // the position isn't known here, so callers reporting one fill it in with `at()`
+            Err(NybbleError::InvalidDigit {
+                position: 0,
+                found: n,
+            })
+        }
+    }
+
+    /// Adds two decimal digits and an incoming carry, returning the result digit and the
+    /// outgoing carry.
+    ///
+    /// # Panics
+    /// In debug builds, panics if either digit is greater than 9 or the carry is greater than 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use infinint::nybble::decimal_add_with_carry;
+    ///
+    /// assert_eq!(decimal_add_with_carry(4, 3, 0), (7, 0));
+    /// assert_eq!(decimal_add_with_carry(9, 9, 1), (9, 1));
+    /// ```
+    pub fn decimal_add_with_carry(n: u8, m: u8, carry: u8) -> (u8, u8) {
+        debug_assert!(n < 10 && m < 10, "digit out of range: {}, {}", n, m);
+        debug_assert!(carry <= 1, "carry out of range: {}", carry);
+        let result = n + m + carry;
+        let carry = result / 10;
+        let result = result % 10;
+        (result, carry)
+    }
+
+    pub(crate) fn decimal_mul_with_carry(n: u8, m: u8, carry: u8) -> (u8, u8) {
+        debug_assert!(n < 10 && m < 10, "digit out of range: {}, {}", n, m);
+        debug_assert!(carry <= 8, "carry out of range: {}", carry);
+        // at most 9 * 9 + 8, so the carry is a single digit
+        let result = n * m + carry;
+        let carry = result / 10;
+        let result = result % 10;
+        (result, carry)
+    }
+
+    /// Subtracts a decimal digit and an incoming borrow from another digit, returning the result
+    /// digit and the outgoing borrow.
+    ///
+    /// # Panics
+    /// In debug builds, panics if either digit is greater than 9 or the borrow is greater than 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use infinint::nybble::decimal_subtract_with_carry;
+    ///
+    /// assert_eq!(decimal_subtract_with_carry(7, 3, 0), (4, 0));
+    /// assert_eq!(decimal_subtract_with_carry(0, 1, 0), (9, 1));
+    /// ```
+    pub fn decimal_subtract_with_carry(n: u8, m: u8, carry: u8) -> (u8, u8) {
+        debug_assert!(n < 10 && m < 10, "digit out of range: {}, {}", n, m);
+        debug_assert!(carry <= 1, "carry out of range: {}", carry);
+        let (result, carry) = if n >= (m + carry) {
+            (n - m - carry, 0)
+        } else {
+            ((n + 10) - m - carry, 1)
+        };
+        (result, carry)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Infinint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // a negative zero is written as plain zero, so the round trip preserves equality and sign
+        if serializer.is_human_readable() {
+            if self.is_zero() {
+                serializer.serialize_str("0")
+            } else {
+                serializer.serialize_str(&format!("{:#}", self))
+            }
+        } else {
+            // sign byte followed by the packed digits
+            let mut bytes = Vec::with_capacity(self.digits_vec.len() + 1);
+            bytes.push((self.negative && !self.is_zero()) as u8);
+            bytes.extend_from_slice(&self.digits_vec);
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Infinint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Infinint, D::Error> {
+        struct InfinintVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for InfinintVisitor {
+            type Value = Infinint;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal integer string or a packed Infinint byte array")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Infinint, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Infinint, E> {
+                match v.split_first() {
+                    Some((0, digits_vec)) => Infinint::from_bytes(false, digits_vec),
+                    Some((1, digits_vec)) => Infinint::from_bytes(true, digits_vec),
+                    _ => return Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+                }
+                .map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(InfinintVisitor)
+        } else {
+            deserializer.deserialize_bytes(InfinintVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infinint_declaration() {
+        let test = Infinint::from(1998);
+        assert!(!test.negative);
+        assert_eq!(test.digits_vec, [0b1000_1001, 0b1001_0001]);
+    }
+
+    #[test]
+    fn with_capacity_reserve() {
+        for n in [0, 1, 2, 7, 100, 1001].iter() {
+            let x = Infinint::with_capacity(*n);
+            assert!(x.is_zero() && !x.negative);
+            assert!(x.digits_vec.capacity() * 2 >= *n);
+        }
+
+        let mut x = Infinint::from(12_345);
+        x.reserve(101);
+        assert!(x.digits_vec.capacity() * 2 >= x.num_digits() + 101);
+        assert_eq!(x, Infinint::from(12_345));
+    }
+
+    #[test]
+    fn is_canonical() {
+        let values: [i128; 8] = [0, 1, -1, 10, 100, -1998, i128::MAX, i128::MIN];
+        for n in values.iter() {
+            assert!(Infinint::from(*n).is_canonical(), "{}", n);
+        }
+        assert!(Infinint::new().is_canonical());
+        assert!((-Infinint::new()).is_canonical());
+        assert!((&Infinint::from(1000) - &Infinint::from(1000)).is_canonical());
+        assert!((&Infinint::from(-1000) + &Infinint::from(999)).is_canonical());
+        assert!(Infinint::factorial(50).is_canonical());
+        assert!(Infinint::with_capacity(100).is_canonical());
+
+        let padded = Infinint {
+            negative: false,
+            digits_vec: vec![0b0111_0011, 0b0001_0000, 0],
+        };
+        assert!(!padded.is_canonical());
+        assert!(padded.normalized().is_canonical());
+        let padded_zero = Infinint {
+            negative: false,
+            digits_vec: vec![0, 0],
+        };
+        assert!(!padded_zero.is_canonical());
+        let negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0],
+        };
+        assert!(!negative_zero.is_canonical());
+        assert!(negative_zero.normalized().is_canonical());
+        let empty = Infinint {
+            negative: false,
+            digits_vec: Vec::new(),
+        };
+        assert!(!empty.is_canonical());
+        let bad_nybble = Infinint {
+            negative: false,
+            digits_vec: vec![0x1A],
+        };
+        assert!(!bad_nybble.is_canonical());
+    }
+
+    #[test]
+    fn normalized_shrink_to_fit() {
+        let padded = Infinint {
+            negative: true,
+            digits_vec: vec![0b0111_0011, 0b0001_0000, 0, 0],
+        };
+        let normalized = padded.clone().normalized();
+        assert_eq!(normalized.digits_vec, [0b0111_0011, 0b0001_0000]);
+        assert_eq!(normalized, Infinint::from(-137));
+
+        let negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0, 0, 0],
+        };
+        let normalized = negative_zero.normalized();
+        assert_eq!(normalized.digits_vec, [0]);
+        assert!(!normalized.negative);
+
+        let mut x = padded;
+        x.reserve(1000);
+        x.shrink_to_fit();
+        assert_eq!(x.digits_vec, [0b0111_0011, 0b0001_0000]);
+        assert!(x.digits_vec.capacity() < 1000 / 2);
+    }
+
+    #[test]
+    fn simple_addition_subtraction() {
+        for x in 0..100 {
+            for y in 0..100 {
+                let a = Infinint::from(x);
+                let b = Infinint::from(y);
+                assert_eq!(&a + &b, Infinint::from(x + y));
+                assert_eq!(&a - &b, Infinint::from(x - y));
+            }
+        }
+    }
+
+    #[test]
+    fn complex_addition_subtraction() {
+        for x in -25..25 {
+            for y in -25..25 {
+                let a = Infinint::from(x);
+                let b = Infinint::from(y);
+                assert_eq!(&a + &b, Infinint::from(x + y));
+                assert_eq!(&a - &b, Infinint::from(x - y));
+            }
+        }
+    }
+
+    #[test]
+    fn primitive_addition_subtraction() {
+        for x in -50..50 {
+            for y in [-10_i128, -1, 0, 1, 5, 99].iter() {
+                let a = Infinint::from(x);
+                let x = i128::from(x);
+                assert_eq!(&a + *y, Infinint::from(x + y));
+                assert_eq!(&a - *y, Infinint::from(x - y));
+                assert_eq!(*y + &a, Infinint::from(y + x));
+                assert_eq!(*y - &a, Infinint::from(y - x));
+                assert_eq!(a.clone() + *y, Infinint::from(x + y));
+                assert_eq!(a.clone() - *y, Infinint::from(x - y));
+                assert_eq!(*y + a.clone(), Infinint::from(y + x));
+                assert_eq!(*y - a.clone(), Infinint::from(y - x));
+            }
+        }
+
+        let big = Infinint::from(u128::MAX);
+        let expected: Infinint = "340282366920938463463374607431768211460".parse().unwrap();
+        assert_eq!(&big + 5, expected);
+        assert_eq!(&expected - 5, big);
+    }
+
+    #[test]
+    fn multiplication() {
+        for x in -25..25 {
+            for y in -25..25 {
+                let a = Infinint::from(x);
+                let b = Infinint::from(y);
+                assert_eq!(&a * &b, Infinint::from(x * y));
+            }
+        }
+        let a = Infinint::from(123_456_789_u64);
+        let b = Infinint::from(987_654_321_u64);
+        assert_eq!(&a * &b, Infinint::from(121_932_631_112_635_269_u64));
+        assert!(!(Infinint::from(-5) * Infinint::from(0)).negative());
+    }
+
+    #[test]
+    fn parity() {
+        assert!(Infinint::from(0).is_even());
+        assert!(!Infinint::from(0).is_odd());
+        for x in -50..50 {
+            let a = Infinint::from(x);
+            assert_eq!(a.is_even(), x % 2 == 0);
+            assert_eq!(a.is_odd(), x % 2 != 0);
+        }
+        assert!(Infinint::from(12_345_678_901_234_u64).is_even());
+        assert!(Infinint::from(12_345_678_901_235_u64).is_odd());
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn num_bigint_round_trip() {
+        use num_bigint::BigInt;
+
+        let digits = "-30414093201713378043612608166064768844377641568960512000000000000";
+        let x: Infinint = digits.parse().unwrap();
+        let big: BigInt = digits.parse().unwrap();
+        assert_eq!(BigInt::from(&x), big);
+        assert_eq!(Infinint::from(&big), x);
+        assert_eq!(Infinint::from(&BigInt::from(&x)), x);
+        assert_eq!(BigInt::from(&Infinint::from(&big)), big);
+
+        for value in [0, 7, -7, 1998, -1_000_000].iter() {
+            let x = Infinint::from(*value);
+            assert_eq!(BigInt::from(&x), BigInt::from(*value));
+            assert_eq!(Infinint::from(&BigInt::from(*value)), x);
+        }
+        assert!(!Infinint::from(&BigInt::from(0)).negative());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_with_digits() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1998);
+        for num_digits in [1, 2, 3, 17, 100].iter() {
+            let x = Infinint::random_with_digits(&mut rng, *num_digits);
+            assert_eq!(x.num_digits(), *num_digits);
+            assert!(!x.negative() && !x.is_zero());
+        }
+
+        let samples: Vec<Infinint> = (0..1000)
+            .map(|_| Infinint::random_with_digits(&mut rng, 20))
+            .collect();
+        assert!(samples.iter().all(|x| x.num_digits() == 20));
+        assert!(samples.iter().any(|x| *x != samples[0]));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_zero_one() {
+        use num_traits::{One, Zero};
+
+        assert_eq!(Infinint::zero(), Infinint::from(0));
+        assert_eq!(Infinint::one(), Infinint::from(1));
+        assert!(Zero::is_zero(&Infinint::zero()));
+        assert!(!Zero::is_zero(&Infinint::one()));
+        assert!(Infinint::one().is_one());
+    }
+
+    #[test]
+    fn to_f64() {
+        assert_eq!(Infinint::from(1000).to_f64(), 1000.0);
+        assert_eq!(Infinint::from(-1000).to_f64(), -1000.0);
+        assert_eq!(Infinint::from(0).to_f64(), 0.0);
+        assert!(Infinint::from(0).to_f64().is_sign_positive());
+        assert_eq!(Infinint::from(u64::MAX).to_f64(), u64::MAX as f64);
+        assert_eq!(Infinint::from(u128::MAX).to_f64(), u128::MAX as f64);
+        // 2^53 + 1 is a tie between two f64s, and rounds to the even one
+        assert_eq!(
+            Infinint::from(9_007_199_254_740_993_i64).to_f64(),
+            9_007_199_254_740_992.0
+        );
+
+        let mut digits = vec![0; 399];
+        digits.push(1);
+        let huge = Infinint::from_digits(false, &digits).unwrap();
+        assert_eq!(huge.to_f64(), f64::INFINITY);
+        assert_eq!((-&huge).to_f64(), f64::NEG_INFINITY);
+
+        let max: Infinint = format!("{:.0}", f64::MAX).parse().unwrap();
+        assert_eq!(max.to_f64(), f64::MAX);
+        assert_eq!(Infinint::from(10).pow(308).to_f64(), 1e308);
+        assert_eq!(Infinint::from(10).pow(309).to_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Infinint::from(1000)), "1,000");
+        assert_eq!(format!("{}", Infinint::from(1_000_000)), "1,000,000");
+        assert_eq!(format!("{}", Infinint::from(1_234_567)), "1,234,567");
+        assert_eq!(format!("{}", Infinint::from(-123_456)), "-123,456");
+
+        // no leading comma, whatever the number of digits
+        let cases = [
+            ("1", "1"),
+            ("12", "12"),
+            ("123", "123"),
+            ("1234", "1,234"),
+            ("12345", "12,345"),
+            ("123456", "123,456"),
+            ("1234567", "1,234,567"),
+            ("123456789012", "123,456,789,012"),
+            ("1234567890123", "1,234,567,890,123"),
+        ];
+        for (digits, expected) in cases.iter() {
+            let x: Infinint = digits.parse().unwrap();
+            assert_eq!(format!("{}", x), *expected);
+            assert_eq!(format!("{}", -&x), format!("-{}", expected));
+            assert_eq!(format!("{:#}", x), *digits);
+        }
+        assert_eq!(format!("{}", Infinint::from(0)), "0");
+        assert_eq!(format!("{}", Infinint::from(7)), "7");
+        assert_eq!(format!("{:#}", Infinint::from(-1_000_000)), "-1000000");
+    }
+
+    #[test]
+    fn display_sign_plus() {
+        assert_eq!(format!("{:+}", Infinint::from(5)), "+5");
+        assert_eq!(format!("{:+}", Infinint::from(-5)), "-5");
+        assert_eq!(format!("{:+}", Infinint::from(1234)), "+1,234");
+        assert_eq!(format!("{:+#}", Infinint::from(1234)), "+1234");
+        assert_eq!(format!("{:+}", Infinint::from(0)), "+0");
+
+        let negative_zero = -&Infinint::new();
+        assert_eq!(format!("{}", negative_zero), "0");
+        assert_eq!(format!("{:+}", negative_zero), "+0");
+        assert_eq!(format!("{:03}", negative_zero), "000");
+        assert_eq!(
+            format!("{:+}", Infinint::from(-7).format_grouped('_', 3)),
+            "-7"
+        );
+    }
+
+    #[test]
+    fn display_padding() {
+        let x = Infinint::from(1234);
+        assert_eq!(format!("{:08}", x), "0,001,234");
+        assert_eq!(format!("{:07}", x), "001,234");
+        assert_eq!(format!("{:04}", x), "1,234");
+        assert_eq!(format!("{:08}", -&x), "-001,234");
+        assert_eq!(format!("{:+08}", x), "+001,234");
+        assert_eq!(format!("{:#08}", x), "00001234");
+        assert_eq!(format!("{:#08}", -&x), "-0001234");
+        assert_eq!(format!("{:>10}", x), "     1,234");
+        assert_eq!(format!("{:<10}|", x), "1,234     |");
+        assert_eq!(format!("{:*^9}", x), "**1,234**");
+        assert_eq!(format!("{:>#10}", -&x), "     -1234");
+        assert_eq!(format!("{:03}", Infinint::from(0)), "000");
+
+        let y = Infinint::from(1_234_567);
+        let grouped = y.format_grouped('_', 4);
+        assert_eq!(format!("{:010}", grouped), "0_0123_4567");
+        assert_eq!(format!("{:>10}", grouped), "  123_4567");
+    }
+
+    #[test]
+    fn display_dynamic_width_precision() {
+        let x = Infinint::from(1234);
+        assert_eq!(format!("{:1$}", x, 10), "     1,234");
+        assert_eq!(format!("{:>width$}", -&x, width = 8), "  -1,234");
+        assert_eq!(format!("{:<1$}|", x, 3), "1,234|");
+        assert_eq!(format!("{:0width$}", x, width = 7), "001,234");
+
+        let plain = x.format_grouped(',', 0);
+        let grouped = x.format_grouped(',', 3);
+        assert_eq!(format!("{:.6}", grouped), "001,234");
+        assert_eq!(format!("{:.6}", plain), "001234");
+        assert_eq!(format!("{:.6}", (-&x).format_grouped(',', 0)), "-001234");
+        assert_eq!(format!("{:.2}", grouped), "1,234");
+        assert_eq!(
+            format!("{:.*}", 3, Infinint::from(7).format_grouped(',', 0)),
+            "007"
+        );
+        assert_eq!(
+            format!("{:.3}", Infinint::new().format_grouped(',', 0)),
+            "000"
+        );
+        assert_eq!(format!("{:>10.6}", plain), "    001234");
+        assert_eq!(format!("{:>1$.2$}", plain, 10, 6), "    001234");
+        // zero-padding still fills the whole width when the precision asks for fewer digits
+        assert_eq!(format!("{:08.6}", plain), "00001234");
+
+        // the default Display abbreviates to the precision instead
+        assert_eq!(format!("{:.*}", 2, x), "1.2×10^3");
+        assert_eq!(format!("{:>1$.2$}", x, 10, 2), "  1.2×10^3");
+    }
+
+    #[test]
+    fn display_precision() {
+        let x: Infinint = "123456789012345678901234567890".parse().unwrap();
+        assert_eq!(format!("{:.3}", x), "1.23×10^29");
+        assert_eq!(format!("{:.3}", -&x), "-1.23×10^29");
+        assert_eq!(format!("{:+.3}", x), "+1.23×10^29");
+        assert_eq!(format!("{:.1}", x), "1×10^29");
+        assert_eq!(format!("{:.0}", x), "1×10^29");
+        assert_eq!(format!("{:#.3}", x), "1.23×10^29");
+        assert_eq!(format!("{:>12.3}", x), "  1.23×10^29");
+        assert_eq!(format!("{:*<12.3}", -&x), "-1.23×10^29*");
+        assert_eq!(format!("{:^14.3}", x), "  1.23×10^29  ");
+        assert_eq!(format!("{:012.3}", -&x), "-01.23×10^29");
+
+        // the mantissa is truncated, not rounded
+        assert_eq!(format!("{:.2}", Infinint::from(-99_999)), "-9.9×10^4");
+
+        // no precision, or one covering every digit, shows the whole number
+        assert_eq!(format!("{}", x), "123,456,789,012,345,678,901,234,567,890");
+        assert_eq!(format!("{:#}", x), "123456789012345678901234567890");
+        assert_eq!(format!("{:.30}", x), format!("{}", x));
+        assert_eq!(format!("{:.4}", Infinint::from(1234)), "1,234");
+        assert_eq!(format!("{:#.6}", Infinint::from(-1234)), "-1234");
+        assert_eq!(format!("{:.0}", Infinint::new()), "0");
+    }
+
+    #[test]
+    fn to_fixed_width() {
+        let x = Infinint::from(42);
+        assert_eq!(x.to_fixed_width(5, '0'), "00042");
+        assert_eq!(x.to_fixed_width(5, ' '), "   42");
+        assert_eq!(x.to_fixed_width(2, '0'), "42");
+        assert_eq!((-&x).to_fixed_width(4, '*'), "**42");
+        assert_eq!(Infinint::new().to_fixed_width(3, ' '), "  0");
+        assert_eq!(x.to_fixed_width(4, '·').chars().count(), 4);
+
+        let y = Infinint::from(1_234_567);
+        assert_eq!(y.to_fixed_width(7, ' '), "1234567");
+        assert_eq!(y.to_fixed_width(6, ' '), "12345…");
+        assert_eq!(y.to_fixed_width(1, ' '), "…");
+        assert_eq!(y.to_fixed_width(0, ' '), "");
+        for width in 0..12 {
+            assert_eq!(y.to_fixed_width(width, '0').chars().count(), width);
+        }
+    }
+
+    #[test]
+    fn to_engineering_string() {
+        let eng = |n: i128, sig_figs| Infinint::from(n).to_engineering_string(sig_figs);
+        assert_eq!(eng(0, 3), "0.00e0");
+        assert_eq!(eng(7, 1), "7e0");
+        assert_eq!(eng(999, 3), "999e0");
+        assert_eq!(eng(999, 5), "999.00e0");
+        assert_eq!(eng(1000, 1), "1e3");
+        assert_eq!(eng(1000, 4), "1.000e3");
+        assert_eq!(eng(12_345, 4), "12.34e3");
+        assert_eq!(eng(999_999, 3), "999e3");
+        assert_eq!(eng(999_999, 1), "900e3");
+        assert_eq!(eng(1_000_000, 2), "1.0e6");
+        assert_eq!(eng(-1_000_000, 0), "-1e6");
+        assert_eq!(eng(123_456_789, 6), "123.456e6");
+        assert_eq!(eng(i128::MAX, 4), "170.1e36");
+        assert_eq!(eng(i128::MIN, 4), "-170.1e36");
+    }
+
+    #[test]
+    fn debug_alternate() {
+        assert_eq!(
+            format!("{:#?}", Infinint::from(-1998)),
+            "Infinint { negative: true, num_digits: 4, digits_vec: [89 91] }"
+        );
+        assert_eq!(
+            format!("{:#?}", Infinint::new()),
+            "Infinint { negative: false, num_digits: 1, digits_vec: [00] }"
+        );
+
+        let big = Infinint::from(10).pow(40);
+        let summary = format!("{:#?}", big);
+        assert!(summary.contains("num_digits: 41"));
+        assert!(summary.contains("00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 10]"));
+        assert!(!summary.contains('\n'));
+
+        // the plain form keeps the per-byte listing
+        assert!(format!("{:?}", big).contains("0001_0000 -> (1, 0)"));
+    }
+
+    #[test]
+    fn to_plain_string() {
+        assert_eq!(Infinint::new().to_plain_string(), "0");
+        assert_eq!((-Infinint::new()).to_plain_string(), "0");
+        assert_eq!(Infinint::from(-1998).to_plain_string(), "1998");
+        assert_eq!(Infinint::from(1_000_000).to_plain_string(), "1000000");
+        assert_eq!(Infinint::from(1_000_000).to_string(), "1,000,000");
+        assert_eq!(
+            Infinint::from_bytes(false, &[0x10, 0x00, 0x00])
+                .unwrap()
+                .to_plain_string(),
+            "1"
+        );
+
+        let x = Infinint::factorial(40);
+        assert_eq!(x.to_plain_string(), format!("{:#}", x));
+    }
+
+    #[test]
+    fn format_grouped_min_digits() {
+        let grouped = |n: i128| {
+            Infinint::from(n)
+                .format_grouped(',', 3)
+                .min_digits(5)
+                .to_string()
+        };
+        assert_eq!(grouped(999), "999");
+        assert_eq!(grouped(1000), "1000");
+        assert_eq!(grouped(9999), "9999");
+        assert_eq!(grouped(-9999), "-9999");
+        assert_eq!(grouped(10_000), "10,000");
+        assert_eq!(grouped(-10_000), "-10,000");
+        assert_eq!(grouped(1_234_567), "1,234,567");
+
+        let x = Infinint::from(1000);
+        assert_eq!(
+            format!("{:08}", x.format_grouped(',', 3).min_digits(5)),
+            "00001000"
+        );
+        assert_eq!(x.format_grouped(',', 3).min_digits(4).to_string(), "1,000");
+        assert_eq!(
+            Infinint::from(123_456)
+                .format_indian()
+                .min_digits(7)
+                .to_string(),
+            "123456"
+        );
+    }
+
+    #[test]
+    fn format_indian() {
+        assert_eq!(
+            Infinint::from(1_234_567).format_indian().to_string(),
+            "12,34,567"
+        );
+        assert_eq!(
+            Infinint::from(10_000_000).format_indian().to_string(),
+            "1,00,00,000"
+        );
+        assert_eq!(
+            Infinint::from(-123_456).format_indian().to_string(),
+            "-1,23,456"
+        );
+        assert_eq!(Infinint::from(999).format_indian().to_string(), "999");
+        assert_eq!(Infinint::from(1000).format_indian().to_string(), "1,000");
+        assert_eq!(
+            format!("{:012}", Infinint::from(1_234_567).format_indian()),
+            "00,12,34,567"
+        );
+    }
+
+    #[test]
+    fn format_grouped() {
+        let x = Infinint::from(1_234_567);
+        assert_eq!(x.format_grouped('_', 3).to_string(), "1_234_567");
+        assert_eq!(x.format_grouped(' ', 4).to_string(), "123 4567");
+        assert_eq!(x.format_grouped(',', 0).to_string(), "1234567");
+        assert_eq!(x.format_grouped('.', 1).to_string(), "1.2.3.4.5.6.7");
+        assert_eq!(x.format_grouped('.', 7).to_string(), "1234567");
+        assert_eq!(x.format_grouped('.', 8).to_string(), "1234567");
+        assert_eq!(
+            Infinint::from(-123_456).format_grouped('_', 3).to_string(),
+            "-123_456"
+        );
+        assert_eq!(Infinint::from(0).format_grouped('_', 3).to_string(), "0");
+
+        let indian = |x: i64| {
+            Infinint::from(x)
+                .format_grouped(',', 2)
+                .first_group_size(3)
+                .to_string()
+        };
+        assert_eq!(indian(123), "123");
+        assert_eq!(indian(1_234), "1,234");
+        assert_eq!(indian(12_345_678), "1,23,45,678");
+        assert_eq!(indian(-123_456_789), "-12,34,56,789");
+        assert_eq!(
+            x.format_grouped(',', 2).first_group_size(0).to_string(),
+            "1234567"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("1998".parse::<Infinint>(), Ok(Infinint::from(1998)));
+        assert_eq!("-0042".parse::<Infinint>(), Ok(Infinint::from(-42)));
+        assert_eq!("+7".parse::<Infinint>(), Ok(Infinint::from(7)));
+        assert!(!"-0".parse::<Infinint>().unwrap().negative());
+        assert_eq!("".parse::<Infinint>(), Err(ParseInfinintError::Empty));
+        assert_eq!("-".parse::<Infinint>(), Err(ParseInfinintError::Empty));
+        assert_eq!(
+            "1,000".parse::<Infinint>(),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 1,
+                found: ','
+            })
+        );
+    }
+
+    #[test]
+    fn parse_radix_prefix() {
+        assert_eq!("0xff".parse::<Infinint>(), Ok(Infinint::from(255)));
+        assert_eq!("0XFF".parse::<Infinint>(), Ok(Infinint::from(255)));
+        assert_eq!("0b101".parse::<Infinint>(), Ok(Infinint::from(5)));
+        assert_eq!("0o777".parse::<Infinint>(), Ok(Infinint::from(511)));
+        assert_eq!("-0x10".parse::<Infinint>(), Ok(Infinint::from(-16)));
+        assert_eq!("+0B11".parse::<Infinint>(), Ok(Infinint::from(3)));
+        assert!(!"-0x0".parse::<Infinint>().unwrap().negative());
+        assert_eq!(
+            "0xffffffffffffffffffffffffffffffff".parse::<Infinint>(),
+            Ok(Infinint::from(u128::MAX))
+        );
+
+        // without a prefix the digits stay decimal, leading zeros included
+        assert_eq!("255".parse::<Infinint>(), Ok(Infinint::from(255)));
+        assert_eq!("0101".parse::<Infinint>(), Ok(Infinint::from(101)));
+        assert_eq!("0".parse::<Infinint>(), Ok(Infinint::new()));
+        assert_eq!(Infinint::try_from("0x1f"), Ok(Infinint::from(31)));
+
+        assert_eq!("0x".parse::<Infinint>(), Err(ParseInfinintError::Empty));
+        assert_eq!("-0b".parse::<Infinint>(), Err(ParseInfinintError::Empty));
+        assert_eq!(
+            "-0b102".parse::<Infinint>(),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 5,
+                found: '2'
+            })
+        );
+        assert_eq!(
+            "0xfg".parse::<Infinint>(),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 3,
+                found: 'g'
+            })
+        );
+        assert_eq!(
+            "1x5".parse::<Infinint>(),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 1,
+                found: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_char() {
+        assert_eq!(Infinint::try_from('7'), Ok(Infinint::from(7)));
+        assert_eq!(Infinint::try_from('0'), Ok(Infinint::new()));
+        assert_eq!(
+            Infinint::try_from('x'),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 0,
+                found: 'x'
+            })
+        );
+        assert!(Infinint::try_from('a').is_err());
+        assert!(Infinint::try_from('٣').is_err());
+
+        let folded = "1998".chars().fold(Infinint::new(), |acc, c| {
+            &(&acc * &Infinint::from(10)) + &Infinint::try_from(c).unwrap()
+        });
+        assert_eq!(folded, Infinint::from(1998));
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_eq!(Infinint::from_str_radix("ff", 16), Ok(Infinint::from(255)));
+        assert_eq!(
+            Infinint::from_str_radix("-FF", 16),
+            Ok(Infinint::from(-255))
+        );
+        assert_eq!(Infinint::from_str_radix("+101", 2), Ok(Infinint::from(5)));
+        assert_eq!(Infinint::from_str_radix("z", 36), Ok(Infinint::from(35)));
+        assert_eq!(
+            Infinint::from_str_radix("1998", 10),
+            Ok(Infinint::from(1998))
+        );
+        assert!(!Infinint::from_str_radix("-000", 8).unwrap().negative());
+        assert_eq!(
+            Infinint::from_str_radix("ffffffffffffffffffffffffffffffff", 16),
+            Ok(Infinint::from(u128::MAX))
+        );
+
+        assert_eq!(
+            Infinint::from_str_radix("", 16),
+            Err(ParseInfinintError::Empty)
+        );
+        assert_eq!(
+            Infinint::from_str_radix("-", 2),
+            Err(ParseInfinintError::Empty)
+        );
+        assert_eq!(
+            Infinint::from_str_radix("1012", 2),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 3,
+                found: '2'
+            })
+        );
+        assert_eq!(
+            Infinint::from_str_radix("1", 1),
+            Err(ParseInfinintError::InvalidRadix(1))
+        );
+        assert_eq!(
+            Infinint::from_str_radix("1", 37),
+            Err(ParseInfinintError::InvalidRadix(37))
+        );
+    }
+
+    #[test]
+    fn from_ascii() {
+        assert_eq!(Infinint::from_ascii(b"-12345"), Ok(Infinint::from(-12345)));
+        assert_eq!(Infinint::from_ascii(b"+0042"), Ok(Infinint::from(42)));
+        assert_eq!(Infinint::from_ascii(b"1998"), Ok(Infinint::from(1998)));
+        assert!(!Infinint::from_ascii(b"-000").unwrap().negative());
+        assert_eq!(
+            Infinint::from_ascii(b"12x45"),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 2,
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            Infinint::from_ascii(b"-1\xff"),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 2,
+                found: '\u{ff}'
+            })
+        );
+        assert_eq!(
+            Infinint::from_ascii(b"--1"),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 1,
+                found: '-'
+            })
+        );
+        assert_eq!(Infinint::from_ascii(b""), Err(ParseInfinintError::Empty));
+        assert_eq!(Infinint::from_ascii(b"-"), Err(ParseInfinintError::Empty));
+
+        let x = Infinint::factorial(90);
+        let text = format!("{:#}", x);
+        assert_eq!(Infinint::from_ascii(text.as_bytes()), Ok(x));
+    }
+
+    #[test]
+    fn from_str_max() {
+        assert_eq!(Infinint::from_str_max("1998", 4), Ok(Infinint::from(1998)));
+        assert_eq!(Infinint::from_str_max("+1998", 4), Ok(Infinint::from(1998)));
+        assert_eq!(
+            Infinint::from_str_max("-1998", 10),
+            Ok(Infinint::from(-1998))
+        );
+        assert_eq!(
+            Infinint::from_str_max("01998", 4),
+            Err(ParseInfinintError::TooLong { max_digits: 4 })
+        );
+        assert_eq!(
+            Infinint::from_str_max("19x8", 4),
+            Err(ParseInfinintError::InvalidDigit {
+                position: 2,
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            Infinint::from_str_max("", 0),
+            Err(ParseInfinintError::Empty)
+        );
+
+        // the length is checked up front, so the invalid tail is never looked at
+        let max_digits = 1_000;
+        let mut huge = "9".repeat(max_digits + 1);
+        huge.push('x');
+        assert_eq!(
+            Infinint::from_str_max(&huge[..max_digits + 1], max_digits),
+            Err(ParseInfinintError::TooLong { max_digits })
+        );
+        assert_eq!(
+            Infinint::from_str_max(&huge, max_digits),
+            Err(ParseInfinintError::TooLong { max_digits })
+        );
+        assert_eq!(
+            ParseInfinintError::TooLong { max_digits: 4 }.to_string(),
+            "number has more than 4 digits"
+        );
+    }
+
+    #[test]
+    fn parse_error_display() {
+        let err = "-12x4".parse::<Infinint>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit 'x' at position 3");
+        assert_eq!(
+            ParseInfinintError::Empty.to_string(),
+            "cannot parse integer from empty string"
+        );
+        assert_eq!(
+            ParseInfinintError::InvalidRadix(40).to_string(),
+            "invalid radix 40, must be between 2 and 36"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_error_is_error() {
+        fn parse(src: &str) -> Result<Infinint, Box<dyn std::error::Error>> {
+            Ok(src.parse::<Infinint>()?)
+        }
+        assert_eq!(parse("1998").unwrap(), Infinint::from(1998));
+        let err = parse("19_98").unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ParseInfinintError>(),
+            Some(&ParseInfinintError::InvalidDigit {
+                position: 2,
+                found: '_'
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_grouped() {
+        let x = Infinint::from(1_234_567);
+        let formatted = format!("{}", x);
+        assert_eq!(
+            Infinint::from_str_grouped(&formatted, &[',']),
+            Ok(x.clone())
+        );
+        let formatted = format!("{}", -&x);
+        assert_eq!(Infinint::from_str_grouped(&formatted, &[',']), Ok(-&x));
+        let formatted = x.format_grouped(' ', 4).to_string();
+        assert_eq!(
+            Infinint::from_str_grouped(&formatted, &[' ']),
+            Ok(x.clone())
+        );
+
+        let separators = [',', '_', ' '];
+        let parse = |src: &str| Infinint::from_str_grouped(src, &separators);
+        assert_eq!(parse("1998"), Ok(Infinint::from(1998)));
+        assert_eq!(parse("+1_998"), Ok(Infinint::from(1998)));
+        assert_eq!(parse("12,34,567"), Ok(x));
+        assert_eq!(parse(""), Err(ParseInfinintError::Empty));
+        assert_eq!(parse("-"), Err(ParseInfinintError::Empty));
+
+        let invalid = |position, found| Err(ParseInfinintError::InvalidDigit { position, found });
+        assert_eq!(parse(",123"), invalid(0, ','));
+        assert_eq!(parse("-,123"), invalid(1, ','));
+        assert_eq!(parse("123,"), invalid(3, ','));
+        assert_eq!(parse("1,,234"), invalid(2, ','));
+        assert_eq!(parse("1,_234"), invalid(2, '_'));
+        assert_eq!(parse("1.234"), invalid(1, '.'));
+        assert_eq!(Infinint::from_str_grouped("1,234", &['_']), invalid(1, ','));
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(Infinint::try_from("1998"), Ok(Infinint::from(1998)));
+        assert_eq!(
+            Infinint::try_from(String::from("-42")),
+            Ok(Infinint::from(-42))
+        );
+
+        let err: Result<Infinint, ParseInfinintError> = Infinint::try_from("19x8");
+        assert_eq!(
+            err,
+            Err(ParseInfinintError::InvalidDigit {
+                position: 2,
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            Infinint::try_from(String::new()),
+            Err(ParseInfinintError::Empty)
+        );
+    }
+
+    #[test]
+    fn owned_neg() {
+        assert_eq!(-Infinint::from(5), Infinint::from(-5));
+        assert_eq!(-Infinint::from(-5), Infinint::from(5));
+        assert!(!(-Infinint::from(0)).negative);
+        assert!(!(-Infinint::from(1998)).is_zero());
+        assert_eq!(-(-Infinint::from(1998)), Infinint::from(1998));
+    }
+
+    #[test]
+    fn negative_zero_equality() {
+        let negative_zero = -&Infinint::new();
+        assert_eq!(negative_zero, Infinint::new());
+        assert!(negative_zero > Infinint::from(-1) && negative_zero < Infinint::from(1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let values = [
+            "12345678901234567890123456789012345678901234567890",
+            "-12345678901234567890123456789012345678901234567890",
+            "0",
+            "-1",
+        ];
+        for value in values.iter() {
+            let x: Infinint = value.parse().unwrap();
+
+            let json = serde_json::to_string(&x).unwrap();
+            assert_eq!(json, format!("\"{}\"", value));
+            assert_eq!(serde_json::from_str::<Infinint>(&json).unwrap(), x);
+
+            let encoded = bincode::serialize(&x).unwrap();
+            let decoded: Infinint = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(decoded, x);
+            assert_eq!(decoded.negative(), x.negative());
+        }
+
+        let negative_zero = -&Infinint::new();
+        let json = serde_json::to_string(&negative_zero).unwrap();
+        assert_eq!(json, "\"0\"");
+        assert_eq!(
+            serde_json::from_str::<Infinint>(&json).unwrap(),
+            negative_zero
+        );
+        let encoded = bincode::serialize(&negative_zero).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Infinint>(&encoded).unwrap(),
+            negative_zero
+        );
+
+        assert!(serde_json::from_str::<Infinint>("\"12a\"").is_err());
+        let malformed = bincode::serialize(&[0_u8, 0xAF][..]).unwrap();
+        assert!(bincode::deserialize::<Infinint>(&malformed).is_err());
+    }
+
+    #[test]
+    fn as_bytes() {
+        let x = Infinint::from(1998);
+        assert_eq!(x.as_bytes(), [0b1000_1001, 0b1001_0001]);
+        assert_eq!(x.as_bytes(), x.to_bytes().as_slice());
+        assert_eq!(Infinint::from(-7).as_bytes(), [0x70]);
+        assert_eq!(Infinint::new().as_bytes(), [0]);
+    }
+
+    #[test]
+    fn base256_round_trip() {
+        assert_eq!(Infinint::new().to_be_base256(), [0]);
+        assert_eq!(Infinint::from(255).to_be_base256(), [0xFF]);
+        assert_eq!(Infinint::from(256).to_be_base256(), [0x01, 0x00]);
+        assert_eq!(Infinint::from(1 << 24).to_be_base256(), [0x01, 0, 0, 0]);
+        assert_eq!(
+            Infinint::from(u128::MAX).to_be_base256(),
+            u128::MAX.to_be_bytes()
+        );
+        assert_eq!(Infinint::from_be_base256(false, &[]), Infinint::new());
+        assert_eq!(
+            Infinint::from_be_base256(false, &[0, 0, 0x07, 0xCE]),
+            Infinint::from(1998)
+        );
+        assert!(!Infinint::from_be_base256(true, &[0, 0]).negative());
+
+        let values = [
+            Infinint::from(-1998),
+            Infinint::from(i128::MIN),
+            Infinint::factorial(100),
+            -Infinint::factorial(33),
+        ];
+        for x in values.iter() {
+            let bytes = x.to_be_base256();
+            assert_eq!(Infinint::from_be_base256(x.negative(), &bytes), *x);
+        }
+        for n in 0..=1000u128 {
+            let x = Infinint::from(n);
+            let expected = n.to_be_bytes();
+            let start = expected.iter().position(|b| *b != 0).unwrap_or(15);
+            assert_eq!(x.to_be_base256(), &expected[start..]);
+        }
+    }
+
+    #[test]
+    fn to_radix_be_into() {
+        let mut buf = vec![0xAA; 3];
+        assert_eq!(Infinint::new().to_radix_be_into(16, &mut buf), Some(1));
+        assert_eq!(buf, [0]);
+        assert_eq!(Infinint::from(1998).to_radix_be_into(0, &mut buf), None);
+        assert_eq!(Infinint::from(1998).to_radix_be_into(257, &mut buf), None);
+        assert_eq!(buf, [0]);
+
+        // one buffer reused across values and radixes still gives the right digits each time
+        let values = [
+            Infinint::factorial(60),
+            Infinint::from(255),
+            -Infinint::from(u128::MAX),
+            Infinint::from(1),
+            Infinint::factorial(25),
+        ];
+        let mut buf = Vec::new();
+        for x in values.iter() {
+            for radix in [2u32, 3, 7, 10, 16, 36, 100, 255, 256].iter().copied() {
+                let len = x.to_radix_be_into(radix, &mut buf).unwrap();
+                assert_eq!(len, buf.len());
+                assert!(buf.iter().all(|d| u32::from(*d) < radix));
+                assert!(buf[0] != 0);
+
+                let mut back = Infinint::new();
+                for d in buf.iter() {
+                    back = &(&back * &Infinint::from(radix)) + &Infinint::from(*d);
+                }
+                assert_eq!(back, x.abs(), "radix {}", radix);
+            }
+        }
+
+        // the largest result fixes the capacity, after which converting allocates nothing
+        let mut buf = Vec::new();
+        values[0].to_radix_be_into(2, &mut buf);
+        let ptr = buf.as_ptr();
+        for x in values.iter() {
+            x.to_radix_be_into(16, &mut buf);
+            assert_eq!(buf.as_ptr(), ptr);
+        }
+        assert_eq!(Infinint::from(255).to_radix_be_into(16, &mut buf), Some(2));
+        assert_eq!(buf, [0xF, 0xF]);
+        assert_eq!(Infinint::from(10).to_radix_be_into(10, &mut buf), Some(2));
+        assert_eq!(buf, [1, 0]);
+        assert_eq!(
+            Infinint::from(1998).to_radix_be_into(256, &mut buf),
+            Some(2)
+        );
+        assert_eq!(buf, Infinint::from(1998).to_be_base256());
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn base256_matches_num_bigint() {
+        let x = -Infinint::factorial(75);
+        let (sign, bytes) = num_bigint::BigInt::from(&x).to_bytes_be();
+        assert_eq!(sign, num_bigint::Sign::Minus);
+        assert_eq!(x.to_be_base256(), bytes);
+        assert_eq!(Infinint::from_be_base256(true, &bytes), x);
+
+        let mut buf = Vec::new();
+        for radix in [2u32, 10, 16, 200].iter().copied() {
+            x.to_radix_be_into(radix, &mut buf);
+            assert_eq!(buf, num_bigint::BigInt::from(&x).to_radix_be(radix).1);
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let values: [i64; 7] = [0, 1, -1, 137, -1998, 1_000_000, -123_456_789_012_345];
+        for x in values.iter() {
+            let a = Infinint::from(*x);
+            let b = Infinint::from_bytes(a.negative(), &a.to_bytes()).unwrap();
+            assert_eq!(a, b);
+            assert_eq!(a.negative(), b.negative());
+        }
+
+        assert_eq!(
+            Infinint::from_bytes(false, &[0x12, 0xA0]),
+            Err(NybbleError::InvalidDigit {
+                position: 1,
+                found: 0xA
+            })
+        );
+        assert_eq!(
+            Infinint::from_bytes(false, &[0x1A]),
+            Err(NybbleError::InvalidDigit {
+                position: 0,
+                found: 0xA
+            })
+        );
+        assert_eq!(Infinint::from_bytes(false, &[]), Err(NybbleError::Empty));
+        let padded = Infinint::from_bytes(true, &[0x10, 0x00]).unwrap();
+        assert_eq!(padded.to_bytes(), [0x10]);
+        assert!(!Infinint::from_bytes(true, &[0x00]).unwrap().negative());
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        let values = [
+            Infinint::new(),
+            Infinint::from(7),
+            Infinint::from(1998),
+            Infinint::from(u128::MAX),
+            Infinint::factorial(45),
+        ];
+        for x in values.iter() {
+            assert_eq!(Infinint::try_from(x.as_bytes()).as_ref(), Ok(x));
+        }
+
+        // the sign is not part of the bytes, so a negative comes back as its magnitude
+        let y = Infinint::from(-1998);
+        let z = Infinint::try_from(y.as_bytes()).unwrap();
+        assert_eq!(z, y.abs());
+        assert!(!z.negative());
+
+        let padded: &[u8] = &[0x21, 0x00];
+        assert_eq!(Infinint::try_from(padded), Ok(Infinint::from(12)));
+        let empty: &[u8] = &[];
+        assert_eq!(Infinint::try_from(empty), Err(NybbleError::Empty));
+        let invalid: &[u8] = &[0x12, 0x3D];
+        assert_eq!(
+            Infinint::try_from(invalid),
+            Err(NybbleError::InvalidDigit {
+                position: 1,
+                found: 0xD
+            })
+        );
+    }
+
+    #[test]
+    fn parts_round_trip() {
+        let values = [
+            Infinint::new(),
+            Infinint::from(-1),
+            Infinint::from(1998),
+            -Infinint::factorial(40),
+        ];
+        for x in values.iter() {
+            let (negative, digits_vec) = x.clone().into_parts();
+            assert_eq!(negative, x.negative());
+            assert_eq!(digits_vec, x.as_bytes());
+            let y = Infinint::from_parts(negative, digits_vec).unwrap();
+            assert_eq!(y, *x);
+            assert_eq!(y.negative(), x.negative());
+        }
+
+        // the allocation is handed back and forth rather than copied
+        let mut digits_vec = Vec::with_capacity(64);
+        digits_vec.extend_from_slice(&[0x21, 0x43]);
+        let ptr = digits_vec.as_ptr();
+        let (_, digits_vec) = Infinint::from_parts(false, digits_vec)
+            .unwrap()
+            .into_parts();
+        assert_eq!(digits_vec.as_ptr(), ptr);
+
+        let normalized = Infinint::from_parts(true, vec![0x00, 0x00]).unwrap();
+        assert_eq!(normalized.into_parts(), (false, vec![0]));
+        assert_eq!(
+            Infinint::from_parts(true, vec![0x70, 0x00])
+                .unwrap()
+                .into_parts(),
+            (true, vec![0x70])
+        );
+    }
+
+    #[test]
+    fn parts_invalid() {
+        assert_eq!(
+            Infinint::from_parts(false, Vec::new()),
+            Err(NybbleError::Empty)
+        );
+        assert_eq!(
+            Infinint::from_parts(true, vec![0x12, 0x34, 0xF5]),
+            Err(NybbleError::InvalidDigit {
+                position: 2,
+                found: 0xF
+            })
+        );
+        assert_eq!(
+            Infinint::from_parts(false, vec![0x0B]),
+            Err(NybbleError::InvalidDigit {
+                position: 0,
+                found: 0xB
+            })
+        );
+    }
+
+    #[test]
+    fn encode_decode() {
+        let values: [i64; 7] = [0, 1, -1, 137, -1998, 1_000_000, -123_456_789_012_345];
+        for x in values.iter() {
+            let a = Infinint::from(*x);
+            let b = Infinint::decode(&a.encode()).unwrap();
+            assert_eq!(a, b);
+            assert_eq!(a.negative(), b.negative());
+        }
+
+        // a length that needs more than one varint byte
+        let big = Infinint::factorial(200);
+        let encoded = big.encode();
+        let len = big.digits_vec.len();
+        assert_eq!(encoded[2..4], [(len & 0x7F) as u8 | 0x80, (len >> 7) as u8]);
+        assert_eq!(Infinint::decode(&encoded), Ok(big));
+
+        assert_eq!((-&Infinint::new()).encode(), [1, 0, 1, 0]);
+        assert!(!Infinint::decode(&[1, 1, 1, 0]).unwrap().negative());
+        assert_eq!(Infinint::decode(&[1, 0, 2, 0x10, 0]), Ok(Infinint::from(1)));
+    }
+
+    #[test]
+    fn decode_errors() {
+        let encoded = Infinint::from(-1998).encode();
+
+        // flipping bits in the length byte makes it disagree with the digits
+        let mut corrupted = encoded.clone();
+        corrupted[2] ^= 0x01;
+        assert_eq!(
+            Infinint::decode(&corrupted),
+            Err(DecodeError::UnexpectedEnd)
+        );
+        corrupted[2] = 1;
+        assert_eq!(
+            Infinint::decode(&corrupted),
+            Err(DecodeError::TrailingBytes)
+        );
+        corrupted[2] = 0;
+        assert_eq!(
+            Infinint::decode(&corrupted),
+            Err(DecodeError::InvalidLength)
+        );
+        corrupted[2] = 0x82;
+        assert_eq!(
+            Infinint::decode(&corrupted),
+            Err(DecodeError::UnexpectedEnd)
+        );
+
+        let mut corrupted = encoded.clone();
+        corrupted[4] = 0x1A;
+        assert_eq!(
+            Infinint::decode(&corrupted),
+            Err(DecodeError::InvalidDigit { position: 4 })
+        );
+
+        assert_eq!(Infinint::decode(&[]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(Infinint::decode(&[1]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(Infinint::decode(&[1, 0]), Err(DecodeError::UnexpectedEnd));
+        assert_eq!(
+            Infinint::decode(&[2, 0, 1, 0x10]),
+            Err(DecodeError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            Infinint::decode(&[1, 2, 1, 0x10]),
+            Err(DecodeError::InvalidSign(2))
+        );
+        let mut overlong = vec![1, 0];
+        overlong.extend_from_slice(&[0xFF; 10]);
+        overlong.push(0x01);
+        assert_eq!(Infinint::decode(&overlong), Err(DecodeError::InvalidLength));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_to_read_from() {
+        use std::io::Cursor;
+
+        let values = [
+            Infinint::from(0),
+            Infinint::from(-1998),
+            Infinint::factorial(200),
+        ];
+        let mut cursor = Cursor::new(Vec::new());
+        for x in values.iter() {
+            x.write_to(&mut cursor).unwrap();
+        }
+        // several values written back to back can be read back one at a time
+        let mut cursor = Cursor::new(cursor.into_inner());
+        for x in values.iter() {
+            assert_eq!(Infinint::read_from(&mut cursor).unwrap(), *x);
+        }
+        assert_eq!(
+            Infinint::read_from(&mut cursor).unwrap_err().kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+
+        let mut truncated = Infinint::from(-1998).encode();
+        truncated.pop();
+        let err = Infinint::read_from(&mut truncated.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let err = Infinint::read_from(&mut [2, 0, 1, 0x10].as_ref()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let inner = err.into_inner().unwrap();
+        assert_eq!(
+            inner.downcast_ref::<DecodeError>(),
+            Some(&DecodeError::UnsupportedVersion(2))
+        );
+
+        // a huge length is only a problem once the data runs out
+        let mut huge = vec![1, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F];
+        huge.extend_from_slice(&[0x10; 16]);
+        let err = Infinint::read_from(&mut huge.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn from_iterator() {
+        assert_eq!(
+            [8u8, 9, 9, 1].iter().copied().collect::<Infinint>(),
+            Infinint::from(1998)
+        );
+        assert_eq!(
+            [0u8, 0, 1, 0, 0].iter().copied().collect::<Infinint>(),
+            Infinint::from(100)
+        );
+        assert_eq!(iter::empty().collect::<Infinint>(), Infinint::new());
+        assert_eq!(
+            [0u8, 0, 0].iter().copied().collect::<Infinint>().to_bytes(),
+            [0]
+        );
+
+        let x = Infinint::factorial(50);
+        assert_eq!(x.digits().into_iter().collect::<Infinint>(), x);
+    }
+
+    #[test]
+    #[should_panic(expected = "digit out of range: 10")]
+    fn from_iterator_out_of_range() {
+        let _ = [1u8, 10].iter().copied().collect::<Infinint>();
+    }
+
+    #[test]
+    fn saturating_conversions() {
+        assert_eq!(Infinint::from(1998).to_u128_saturating(), 1998);
+        assert_eq!(Infinint::from(u128::MAX).to_u128_saturating(), u128::MAX);
+        assert_eq!(Infinint::from(-1).to_u128_saturating(), 0);
+        assert_eq!(Infinint::from(-1998).to_i128_saturating(), -1998);
+        assert_eq!(Infinint::from(i128::MAX).to_i128_saturating(), i128::MAX);
+        assert_eq!(Infinint::from(i128::MIN).to_i128_saturating(), i128::MIN);
+        assert_eq!(
+            (Infinint::from(i128::MAX) + 1).to_i128_saturating(),
+            i128::MAX
+        );
+        assert_eq!(
+            (Infinint::from(i128::MIN) - 1).to_i128_saturating(),
+            i128::MIN
+        );
+        assert_eq!(Infinint::new().to_i128_saturating(), 0);
+
+        // 40 digits is more than u128 (39 digits) can hold
+        let x: Infinint = "1234567890123456789012345678901234567890".parse().unwrap();
+        assert_eq!(x.num_digits(), 40);
+        assert_eq!(x.to_u128_saturating(), u128::MAX);
+        assert_eq!(x.to_i128_saturating(), i128::MAX);
+        assert_eq!((-&x).to_u128_saturating(), 0);
+        assert_eq!((-&x).to_i128_saturating(), i128::MIN);
+    }
+
+    #[test]
+    fn digit_iter() {
+        let x = Infinint::from(1998);
+        assert_eq!(x.digit_iter().collect::<Vec<_>>(), x.digits());
+        assert_eq!(x.digit_iter().map(u32::from).sum::<u32>(), 27);
+        assert_eq!(Infinint::new().digit_iter().collect::<Vec<_>>(), [0]);
+        assert_eq!(
+            Infinint::from(-100).digit_iter().collect::<Vec<_>>(),
+            [0, 0, 1]
+        );
+
+        let padded = Infinint::from_bytes(false, &[0x12, 0x30, 0x00]).unwrap();
+        assert_eq!(padded.digit_iter().collect::<Vec<_>>(), padded.digits());
+        let y = Infinint::factorial(77);
+        assert_eq!(y.digit_iter().collect::<Vec<_>>(), y.digits());
+    }
+
+    #[test]
+    fn digit_at() {
+        let x = Infinint::from(1998);
+        assert_eq!(x.digit_at(0), 8);
+        assert_eq!(x.digit_at(1), 9);
+        assert_eq!(x.digit_at(3), 1);
+        assert_eq!(x.digit_at(4), 0);
+        assert_eq!(x.digit_at(usize::MAX), 0);
+        assert_eq!(Infinint::from(-505).digit_at(2), 5);
+
+        let y = Infinint::factorial(30);
+        for (i, d) in y.digits().into_iter().enumerate() {
+            assert_eq!(y.digit_at(i), d);
+        }
+    }
+
+    #[test]
+    fn invalid_nybble_is_typed_error() {
+        // a nybble of 0xB is rejected with an error rather than a panic
+        assert_eq!(
+            Infinint::from_bytes(true, &[0x10, 0x3B]),
+            Err(NybbleError::InvalidDigit {
+                position: 1,
+                found: 0xB
+            })
+        );
+        assert_eq!(
+            Infinint::decode(&[1, 0, 2, 0x10, 0xB0]),
+            Err(DecodeError::InvalidDigit { position: 4 })
+        );
+        assert_eq!(
+            NybbleError::InvalidDigit {
+                position: 1,
+                found: 0xB
+            }
+            .to_string(),
+            "invalid digit 11 at position 1"
+        );
+    }
+
+    #[test]
+    fn count_digit() {
+        assert_eq!(Infinint::from(1999).count_digit(9), 3);
+        assert_eq!(Infinint::from(1999).count_digit(1), 1);
+        assert_eq!(Infinint::from(1999).count_digit(0), 0);
+        assert_eq!(Infinint::from(100).count_digit(0), 2);
+        assert_eq!(Infinint::from(-1000).count_digit(0), 3);
+        assert_eq!(Infinint::new().count_digit(0), 1);
+        assert_eq!(Infinint::new().count_digit(5), 0);
+
+        let x = Infinint::factorial(100);
+        let digits = x.digits();
+        for d in 0..10 {
+            let expected = digits.iter().filter(|digit| **digit == d).count();
+            assert_eq!(x.count_digit(d), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "digit out of range: 10")]
+    #[cfg(debug_assertions)]
+    fn count_digit_out_of_range() {
+        Infinint::from(1998).count_digit(10);
+    }
+
+    #[test]
+    fn mod_pow10() {
+        let x = Infinint::from(123_456);
+        assert_eq!(x.mod_pow10(3), Infinint::from(456));
+        assert_eq!(x.mod_pow10(4), Infinint::from(3456));
+        assert_eq!(x.mod_pow10(6), x);
+        assert_eq!(x.mod_pow10(100), x);
+        assert_eq!(x.mod_pow10(0), Infinint::new());
+        assert_eq!(Infinint::from(5).mod_pow10(3), Infinint::from(5));
+        assert_eq!(Infinint::from(-123_456).mod_pow10(3), Infinint::from(456));
+
+        // zeros left at the top are trimmed
+        let y = Infinint::from(1_000_007);
+        assert_eq!(y.mod_pow10(5), Infinint::from(7));
+        assert_eq!(y.mod_pow10(5).to_bytes(), [0x70]);
+        assert_eq!(Infinint::from(1000).mod_pow10(3).to_bytes(), [0]);
+
+        let z = Infinint::factorial(40);
+        for k in 0..50 {
+            let modulus = Infinint::from(10).pow(k as u32);
+            assert_eq!(z.mod_pow10(k), &z % &modulus, "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn wrapping_add_mod_pow10() {
+        // a six-digit odometer driven a kilometre at a time up to and past its rollover
+        let one = Infinint::one();
+        let mut odometer = Infinint::from(999_997);
+        let mut readings = Vec::new();
+        for _ in 0..5 {
+            odometer = odometer.wrapping_add_mod_pow10(&one, 6);
+            readings.push(odometer.clone());
+        }
+        let expected: Vec<Infinint> = [999_998, 999_999, 0, 1, 2]
+            .iter()
+            .map(|n| Infinint::from(*n))
+            .collect();
+        assert_eq!(readings, expected);
+
+        // sums which stay in range are unchanged
+        let x = Infinint::from(123_456);
+        assert_eq!(
+            x.wrapping_add_mod_pow10(&Infinint::from(1000), 6),
+            Infinint::from(124_456)
+        );
+        assert_eq!(x.wrapping_add_mod_pow10(&Infinint::new(), 6), x);
+        assert_eq!(
+            x.wrapping_add_mod_pow10(&Infinint::from(-456), 6),
+            Infinint::from(123_000)
+        );
+
+        // carries past the top digit and whole multiples of the modulus are dropped
+        assert_eq!(
+            x.wrapping_add_mod_pow10(&Infinint::from(900_000), 6),
+            Infinint::from(23_456)
+        );
+        assert_eq!(x.wrapping_add_mod_pow10(&Infinint::pow10(9), 6), x);
+        assert_eq!(x.wrapping_add_mod_pow10(&x, 0), Infinint::new());
+
+        // negative sums wind back from the top
+        let back =
+            |n: i128, m: i128| Infinint::from(n).wrapping_add_mod_pow10(&Infinint::from(m), 3);
+        assert_eq!(back(0, -1), Infinint::from(999));
+        assert_eq!(back(5, -1005), Infinint::new());
+        assert_eq!(back(-2500, 0), Infinint::from(500));
+        for n in -2000..2000 {
+            assert_eq!(back(n, 7), Infinint::from((n + 7).rem_euclid(1000)));
+        }
+    }
+
+    #[test]
+    fn round_to_pow10() {
+        let round = |n: i128, k: usize| Infinint::from(n).round_to_pow10(k);
+        assert_eq!(round(1234, 2), Infinint::from(1200));
+        assert_eq!(round(1250, 2), Infinint::from(1300));
+        assert_eq!(round(1249, 2), Infinint::from(1200));
+        assert_eq!(round(1299, 2), Infinint::from(1300));
+        assert_eq!(round(-1234, 2), Infinint::from(-1200));
+        assert_eq!(round(-1250, 2), Infinint::from(-1300));
+        assert_eq!(round(-1249, 2), Infinint::from(-1200));
+        assert_eq!(round(1234, 0), Infinint::from(1234));
+        assert_eq!(round(1234, 1), Infinint::from(1230));
+        assert_eq!(round(1235, 1), Infinint::from(1240));
+        assert_eq!(round(1234, 3), Infinint::from(1000));
+        assert_eq!(round(1500, 3), Infinint::from(2000));
+        assert_eq!(round(9999, 2), Infinint::from(10_000));
+        assert_eq!(round(99_999, 3), Infinint::from(100_000));
+        assert_eq!(round(1234, 4), Infinint::new());
+        assert_eq!(round(5000, 4), Infinint::from(10_000));
+        assert_eq!(round(1234, 10), Infinint::new());
+        assert!(!round(-49, 2).negative());
+        assert_eq!(round(-49, 2).to_bytes(), [0]);
+    }
+
+    #[test]
+    fn truncate_significant() {
+        let truncate = |x: i128, n: usize| Infinint::from(x).truncate_significant(n);
+        assert_eq!(truncate(123_456, 2), Infinint::from(120_000));
+        assert_eq!(truncate(123_456, 3), Infinint::from(123_000));
+        assert_eq!(truncate(199_999, 1), Infinint::from(100_000));
+        assert_eq!(truncate(-199_999, 1), Infinint::from(-100_000));
+        assert_eq!(truncate(123_456, 6), Infinint::from(123_456));
+        assert_eq!(truncate(123_456, 100), Infinint::from(123_456));
+        assert_eq!(truncate(0, 3), Infinint::new());
+        assert_eq!(truncate(0, 0), Infinint::new());
+        assert_eq!(truncate(123_456, 0), Infinint::new());
+        assert!(!truncate(-123_456, 0).negative());
+
+        let x = Infinint::factorial(50);
+        let truncated = x.truncate_significant(10);
+        assert_eq!(truncated.num_digits(), x.num_digits());
+        let (x_text, truncated_text) = (x.to_plain_string(), truncated.to_plain_string());
+        assert_eq!(truncated_text[..10], x_text[..10]);
+        assert!(truncated_text[10..].bytes().all(|b| b == b'0'));
+    }
+
+    #[test]
+    fn reverse_digits() {
+        assert_eq!(
+            Infinint::from(12321).reverse_digits(),
+            Infinint::from(12321)
+        );
+        assert_eq!(Infinint::from(1230).reverse_digits(), Infinint::from(321));
+        assert_eq!(Infinint::from(1000).reverse_digits().to_bytes(), [0x10]);
+        assert_eq!(
+            Infinint::from(-1998).reverse_digits(),
+            Infinint::from(-8991)
+        );
+        assert_eq!(Infinint::from(-10).reverse_digits(), Infinint::from(-1));
+        assert_eq!(Infinint::new().reverse_digits(), Infinint::new());
+        assert_eq!(Infinint::from(7).reverse_digits(), Infinint::from(7));
+    }
+
+    #[test]
+    fn is_palindrome() {
+        assert!(Infinint::from(12321).is_palindrome());
+        assert!(Infinint::from(1221).is_palindrome());
+        assert!(Infinint::from(-12321).is_palindrome());
+        assert!(!Infinint::from(12345).is_palindrome());
+        assert!(!Infinint::from(1230).is_palindrome());
+        assert!(!Infinint::from(-10).is_palindrome());
+        for d in 0..10 {
+            assert!(Infinint::from(d).is_palindrome());
+        }
+
+        let x = Infinint::factorial(25);
+        let y: Infinint = format!("{:#}{:#}", x, x.reverse_digits()).parse().unwrap();
+        assert!(y.is_palindrome());
+        assert_eq!(y.reverse_digits(), y);
+    }
+
+    #[test]
+    fn from_primitives() {
+        macro_rules! check {
+            ($($t:ty),*) => {
+                $(
+                    for n in [<$t>::MIN, <$t>::MAX, 0, 1, <$t>::MAX / 3].iter() {
+                        let x = Infinint::from(*n);
+                        assert_eq!(x.to_plain_string(), n.to_string().trim_start_matches('-'));
+                        assert_eq!(x.negative(), n.to_string().starts_with('-'));
+                        assert_eq!(x.to_bytes(), n.to_string().parse::<Infinint>().unwrap().to_bytes());
+                    }
+                )*
+            };
+        }
+        check!(u128, u64, u32, u16, u8, usize, i128, i64, i32, i16, i8, isize);
+
+        assert_eq!(
+            Infinint::from(u128::MAX).to_plain_string(),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(
+            Infinint::from(u64::MAX),
+            Infinint::from(u128::from(u64::MAX))
+        );
+        assert_eq!(Infinint::from(i8::MIN), Infinint::from(-128));
+        assert_eq!(
+            Infinint::from(usize::MAX),
+            Infinint::from(usize::MAX as u128)
+        );
+        assert_eq!(
+            Infinint::from(isize::MIN),
+            Infinint::from(isize::MIN as i128)
+        );
+    }
+
+    #[test]
+    fn fits() {
+        let one = Infinint::from(1);
+        let check = |x: Infinint, u64_: bool, i64_: bool, u128_: bool, i128_: bool| {
+            assert_eq!(x.fits_u64(), u64_, "{} fits u64", x);
+            assert_eq!(x.fits_i64(), i64_, "{} fits i64", x);
+            assert_eq!(x.fits_u128(), u128_, "{} fits u128", x);
+            assert_eq!(x.fits_i128(), i128_, "{} fits i128", x);
+        };
+        check(Infinint::new(), true, true, true, true);
+        check(-Infinint::new(), true, true, true, true);
+        check(Infinint::from(-1), false, true, false, true);
+        check(Infinint::from(u64::MAX), true, false, true, true);
+        check(&Infinint::from(u64::MAX) + &one, false, false, true, true);
+        check(Infinint::from(i64::MAX), true, true, true, true);
+        check(&Infinint::from(i64::MAX) + &one, true, false, true, true);
+        check(Infinint::from(i64::MIN), false, true, false, true);
+        check(&Infinint::from(i64::MIN) - &one, false, false, false, true);
+        check(Infinint::from(i128::MAX), false, false, true, true);
+        check(&Infinint::from(i128::MAX) + &one, false, false, true, false);
+        check(Infinint::from(i128::MIN), false, false, false, true);
+        check(
+            &Infinint::from(i128::MIN) - &one,
+            false,
+            false,
+            false,
+            false,
+        );
+        check(Infinint::from(u128::MAX), false, false, true, false);
+        check(
+            &Infinint::from(u128::MAX) + &one,
+            false,
+            false,
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn primitive_round_trip() {
+        let mut unsigned = vec![0, 1, 2, 9, 99, 100, 101, u128::MAX - 1, u128::MAX];
+        let mut power = 1u128;
+        // every power of ten that fits, with its neighbours, covering both odd and even digit
+        // counts
+        loop {
+            unsigned.extend_from_slice(&[power - 1, power, power + 1]);
+            match power.checked_mul(10) {
+                Some(next) => power = next,
+                None => break,
+            }
+        }
+        unsigned.extend(0..1000);
+
+        for n in unsigned.iter().copied() {
+            let x = Infinint::from(n);
+            assert_eq!(u128::try_from(&x), Ok(n));
+            assert_eq!(x.num_digits(), n.to_string().len());
+            assert_eq!(
+                x.to_bytes(),
+                n.to_string().parse::<Infinint>().unwrap().to_bytes()
+            );
+            if let Ok(n) = i128::try_from(n) {
+                assert_eq!(i128::try_from(&Infinint::from(n)), Ok(n));
+                assert_eq!(i128::try_from(&Infinint::from(-n)), Ok(-n));
+            }
+        }
+        for n in [i128::MIN, i128::MIN + 1, i128::MAX, -1].iter().copied() {
+            assert_eq!(i128::try_from(&Infinint::from(n)), Ok(n));
+        }
+
+        let too_big = Infinint::from(u128::MAX) + 1;
+        assert!(u128::try_from(&too_big).is_err());
+        assert!(u128::try_from(&Infinint::from(-1)).is_err());
+        assert!(i128::try_from(&(Infinint::from(i128::MAX) + 1)).is_err());
+        assert!(i128::try_from(&(Infinint::from(i128::MIN) - 1)).is_err());
+        assert_eq!(u128::try_from(&-Infinint::new()), Ok(0));
+        assert_eq!(
+            TryFromInfinintError(()).to_string(),
+            "out of range integral type conversion attempted"
+        );
+    }
+
+    #[test]
+    fn add_assign() {
+        let values = [
+            0,
+            1,
+            -1,
+            9,
+            -9,
+            99,
+            -99,
+            100,
+            -100,
+            1998,
+            -1998,
+            123_456_789,
+        ];
+        for n in values.iter() {
+            for m in values.iter() {
+                let mut x = Infinint::from(*n);
+                x += &Infinint::from(*m);
+                assert_eq!(x, Infinint::from(n + m), "{} + {}", n, m);
+                assert_eq!(x.to_bytes(), Infinint::from(n + m).to_bytes());
+                assert_eq!(x.negative(), n + m < 0);
+            }
+        }
+
+        let mut y = Infinint::from(999);
+        y += Infinint::from(1);
+        assert_eq!(y, Infinint::from(1000));
+    }
+
+    #[test]
+    fn div_rem_assign() {
+        let two = Infinint::from(2);
+        let mut x = Infinint::from(100);
+        let mut sequence = Vec::new();
+        while !x.is_zero() {
+            sequence.push(i128::try_from(&x).unwrap());
+            x /= &two;
+        }
+        assert_eq!(sequence, [100, 50, 25, 12, 6, 3, 1]);
+
+        let mut y = Infinint::from(-7);
+        y /= &two;
+        assert_eq!(y, Infinint::from(-3));
+
+        let mut z = Infinint::from(1998);
+        z %= &Infinint::from(1000);
+        assert_eq!(z, Infinint::from(998));
+        z %= &Infinint::from(-10);
+        assert_eq!(z, Infinint::from(8));
+        let mut w = Infinint::from(-1998);
+        w %= &Infinint::from(1000);
+        assert_eq!(w, Infinint::from(-998));
+
+        let mut big = Infinint::factorial(50);
+        big /= &Infinint::factorial(48);
+        assert_eq!(big, Infinint::from(50 * 49));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_assign_zero() {
+        let mut x = Infinint::from(1998);
+        x /= &Infinint::new();
+    }
+
+    #[test]
+    fn add_assign_in_place() {
+        // an accumulator with room to spare keeps its buffer across many adds
+        let mut acc: Infinint = "1".repeat(60).parse().unwrap();
+        acc.reserve(10);
+        let ptr = acc.digits_vec.as_ptr();
+        let capacity = acc.digits_vec.capacity();
+        let step = Infinint::from(123_456_789);
+        for _ in 0..10_000 {
+            acc += &step;
+        }
+        assert_eq!(acc.digits_vec.as_ptr(), ptr);
+        assert_eq!(acc.digits_vec.capacity(), capacity);
+
+        let expected =
+            &"1".repeat(60).parse::<Infinint>().unwrap() + &(&step * &Infinint::from(10_000));
+        assert_eq!(acc, expected);
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1998);
+        let mut random_digits =
+            |len: usize| -> Vec<u8> { (0..len).map(|_| rng.gen_range(0..10)).collect() };
+        let lengths = [
+            (1, 1),
+            (2, 2),
+            (3, 2),
+            (7, 7),
+            (64, 64),
+            (65, 3),
+            (100, 37),
+            (301, 299),
+        ];
+        for (n_len, m_len) in lengths.iter() {
+            let n = random_digits(*n_len);
+            let m = random_digits(*m_len);
+            let schoolbook = Infinint::mul_schoolbook(&n, &m);
+            // a threshold of 0 recurses as deep as possible
+            for threshold in [0, 4, 16].iter() {
+                assert_eq!(
+                    Infinint::mul_unpacked(&n, &m, *threshold),
+                    schoolbook,
+                    "{} x {} digits, threshold {}",
+                    n_len,
+                    m_len,
+                    threshold
+                );
+            }
+        }
+
+        // all nines push every carry as far as it can go
+        let nines = vec![9; 257];
+        assert_eq!(
+            Infinint::mul_unpacked(&nines, &nines, 0),
+            Infinint::mul_schoolbook(&nines, &nines)
+        );
+
+        let x = Infinint::factorial(300);
+        let y = Infinint::factorial(200);
+        assert!(x.num_digits() > KARATSUBA_THRESHOLD);
+        assert_eq!(&(&x * &y) / &y, x);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn multiply_cross_check() {
+        use rand::{Rng, SeedableRng};
+
+        // multiplies through the public operator and checks it against schoolbook alone and
+        // against Karatsuba recursing as deep as it can
+        fn cross_checked_product(n: &Infinint, m: &Infinint) -> Infinint {
+            let product = n * m;
+            let (n_digits, m_digits) = (n.digits(), m.digits());
+            let schoolbook = Infinint::mul_schoolbook(&n_digits, &m_digits);
+            let deepest = Infinint::mul_unpacked(&n_digits, &m_digits, 0);
+            assert_eq!(deepest, schoolbook, "{} x {}", n, m);
+            assert_eq!(
+                product.digits_vec,
+                Infinint::digits_vec_from_digits(&schoolbook),
+                "{} x {}",
+                n,
+                m
+            );
+            product
+        }
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1998);
+        for trial in 0..300 {
+            // mostly around the threshold, with some short and some lopsided operands
+            let max_len = if trial % 3 == 0 {
+                20
+            } else {
+                3 * KARATSUBA_THRESHOLD
+            };
+            let n_len = rng.gen_range(1..=max_len);
+            let m_len = rng.gen_range(1..=max_len);
+            let mut n = Infinint::random_with_digits(&mut rng, n_len);
+            let mut m = Infinint::random_with_digits(&mut rng, m_len);
+            if rng.gen() {
+                n = -n;
+            }
+            if rng.gen() {
+                m = -m;
+            }
+
+            let product = cross_checked_product(&n, &m);
+            assert_eq!(product, cross_checked_product(&m, &n));
+            assert_eq!(product.negative(), n.negative() != m.negative());
+            assert_eq!(&product / &m, n);
+        }
+    }
+
+    #[test]
+    fn divide_exact() {
+        let divide = |n: i128, m: i128| Infinint::from(n).divide_exact(&Infinint::from(m));
+        assert_eq!(divide(12, 4), Some(Infinint::from(3)));
+        assert_eq!(divide(13, 4), None);
+        assert_eq!(divide(-12, 4), Some(Infinint::from(-3)));
+        assert_eq!(divide(12, -4), Some(Infinint::from(-3)));
+        assert_eq!(divide(-13, -4), None);
+        assert_eq!(divide(0, 7), Some(Infinint::new()));
+
+        let ratio = Infinint::factorial(60).divide_exact(&Infinint::factorial(58));
+        assert_eq!(ratio, Some(Infinint::from(60 * 59)));
+        let big = &Infinint::factorial(60) + &Infinint::from(1);
+        assert_eq!(big.divide_exact(&Infinint::factorial(30)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn divide_exact_zero() {
+        Infinint::from(12).divide_exact(&Infinint::new());
+    }
+
+    #[test]
+    fn divmod_display() {
+        let mixed = |n: i128, m: i128| Infinint::from(n).divmod_display(&Infinint::from(m));
+        assert_eq!(mixed(17, 5), "3 2/5");
+        assert!(mixed(17, 5).contains('3') && mixed(17, 5).contains('2'));
+        assert_eq!(mixed(-17, 5), "-3 2/5");
+        assert_eq!(mixed(17, -5), "-3 2/5");
+        assert_eq!(mixed(-17, -5), "3 2/5");
+        assert_eq!(mixed(2, 5), "2/5");
+        assert_eq!(mixed(-2, 5), "-2/5");
+        assert_eq!(mixed(15, 5), "3");
+        assert_eq!(mixed(-15, 5), "-3");
+        assert_eq!(mixed(0, -5), "0");
+        assert_eq!(mixed(1_234_567, 1000), "1,234 567/1,000");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn divmod_display_zero() {
+        let _ = Infinint::from(17).divmod_display(&Infinint::new());
+    }
+
+    #[test]
+    fn divmod_small() {
+        let dividends = [
+            Infinint::new(),
+            Infinint::from(1998),
+            Infinint::from(-1998),
+            Infinint::from(u128::MAX),
+            Infinint::factorial(60),
+            -Infinint::factorial(61),
+        ];
+        let divisors = [1, 2, 7, 10, 999, 65_536, 1_000_000_007, u32::MAX];
+        for n in dividends.iter() {
+            for d in divisors.iter() {
+                let (q, r) = n.divmod_small(*d);
+                let (long_q, long_r) = Infinint::long_divmod(n, &Infinint::from(*d));
+                assert_eq!(q, long_q, "{} / {}", n, d);
+                assert_eq!(Infinint::from(r), long_r.abs(), "{} % {}", n, d);
+                assert_eq!(q.negative(), long_q.negative());
+
+                // the general division takes the same path for small divisors
+                let m = Infinint::from(-i64::from(*d));
+                assert_eq!(n / &m, -&q);
+                assert_eq!(n % &m, long_r);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn divmod_small_zero() {
+        Infinint::from(1998).divmod_small(0);
+    }
+
+    #[test]
+    fn zero_one() {
+        assert_eq!(Infinint::zero(), Infinint::from(0));
+        assert_eq!(Infinint::zero().to_bytes(), Infinint::from(0).to_bytes());
+        assert!(Infinint::zero().is_zero());
+        assert_eq!(Infinint::one(), Infinint::from(1));
+        assert_eq!(Infinint::one().to_bytes(), Infinint::from(1).to_bytes());
+        assert_eq!(&Infinint::one() - &Infinint::one(), Infinint::zero());
+    }
+
+    #[test]
+    fn sort_matches_i128() {
+        // values that went through arithmetic which can leave zero bytes behind before trimming,
+        // such as borrows across many digits, alongside negatives and a negated zero
+        let mut pairs: Vec<(Infinint, i128)> = Vec::new();
+        let mut push = |x: Infinint, n: i128| pairs.push((x, n));
+        push(&Infinint::from(100) - &Infinint::from(99), 1);
+        push(&Infinint::from(10_000) - &Infinint::from(9_999), 1);
+        push(&Infinint::from(-10_000) + &Infinint::from(9_999), -1);
+        push(&Infinint::from(1_000_000) - &Infinint::from(999_990), 10);
+        push(&Infinint::from(5) - &Infinint::from(5), 0);
+        push(-&Infinint::new(), 0);
+        push(Infinint::from_bytes(false, &[0x10, 0x00, 0x00]).unwrap(), 1);
+        push(
+            Infinint::from_bytes(true, &[0x99, 0x90, 0x00]).unwrap(),
+            -999,
+        );
+        push(&Infinint::from(-3) * &Infinint::from(0), 0);
+        push(&Infinint::from(123_456) / &Infinint::from(1_000), 123);
+        push(&Infinint::from(-123_456) % &Infinint::from(1_000), -456);
+        push(Infinint::from(i128::MIN), i128::MIN);
+        push(Infinint::from(i128::MAX), i128::MAX);
+        for n in [-1998, -100, -99, -10, -9, 7, 9, 10, 99, 100, 1998].iter() {
+            push(Infinint::from(*n), *n);
+        }
+
+        let mut sorted = pairs.clone();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut expected: Vec<i128> = pairs.iter().map(|pair| pair.1).collect();
+        expected.sort();
+        let sorted: Vec<i128> = sorted.iter().map(|pair| pair.1).collect();
+        assert_eq!(sorted, expected);
+
+        // every value is in canonical form, with no padding bytes and no negative zero
+        for (x, n) in pairs.iter() {
+            assert!(x.is_canonical());
+            assert_eq!(i128::try_from(x), Ok(*n));
+            assert_eq!(x.to_bytes(), Infinint::from(*n).to_bytes());
+            assert_eq!(x.negative(), *n < 0);
+        }
+    }
+
+    #[test]
+    fn binomial() {
+        assert_eq!(Infinint::binomial(5, 2), Infinint::from(10));
+        assert_eq!(Infinint::binomial(10, 5), Infinint::from(252));
+        assert_eq!(Infinint::binomial(10, 0), Infinint::from(1));
+        assert_eq!(Infinint::binomial(10, 10), Infinint::from(1));
+        assert_eq!(Infinint::binomial(0, 0), Infinint::from(1));
+        assert_eq!(Infinint::binomial(3, 5), Infinint::new());
+        assert_eq!(Infinint::binomial(30, 7), Infinint::binomial(30, 23));
+
+        let c_100_50: Infinint = "100891344545564193334812497256".parse().unwrap();
+        assert_eq!(Infinint::binomial(100, 50), c_100_50);
+
+        // each row of Pascal's triangle sums to 2^n
+        let row_sum = (0..=64).fold(Infinint::new(), |acc, k| &acc + &Infinint::binomial(64, k));
+        assert_eq!(row_sum, Infinint::from(2).pow(64));
+    }
+
+    #[test]
+    fn lcm() {
+        let lcm = |n: i128, m: i128| Infinint::from(n).lcm(&Infinint::from(m));
+        assert_eq!(lcm(4, 6), Infinint::from(12));
+        assert_eq!(lcm(-4, 6), Infinint::from(12));
+        assert_eq!(lcm(-4, -6), Infinint::from(12));
+        assert_eq!(lcm(0, 5), Infinint::new());
+        assert_eq!(lcm(5, 0), Infinint::new());
+        assert_eq!(lcm(0, 0), Infinint::new());
+        assert_eq!(lcm(7, 7), Infinint::from(7));
+        assert_eq!(lcm(9, 28), Infinint::from(252));
+
+        let x = Infinint::factorial(20);
+        let y = Infinint::factorial(25);
+        assert_eq!(x.lcm(&y), y);
+        let p: Infinint = "1000000007".parse().unwrap();
+        let q: Infinint = "998244353".parse().unwrap();
+        assert_eq!(p.lcm(&q), &p * &q);
+    }
+
+    #[test]
+    fn nybble_decimal_digits() {
+        assert_eq!(nybble::decimal_digits(0x00), Ok((0, 0)));
+        assert_eq!(nybble::decimal_digits(0x99), Ok((9, 9)));
+        assert_eq!(nybble::decimal_digits(0x10), Ok((1, 0)));
+        assert_eq!(
+            nybble::decimal_digits(0xA0),
+            Err(NybbleError::InvalidDigit {
+                position: 0,
+                found: 0xA
+            })
+        );
+        assert_eq!(
+            nybble::decimal_digits(0x0F),
+            Err(NybbleError::InvalidDigit {
+                position: 0,
+                found: 0xF
+            })
+        );
+    }
+
+    #[test]
+    fn nybble_add_subtract() {
+        assert_eq!(nybble::decimal_add_with_carry(9, 9, 1), (9, 1));
+        assert_eq!(nybble::decimal_add_with_carry(9, 0, 1), (0, 1));
+        assert_eq!(nybble::decimal_add_with_carry(5, 4, 0), (9, 0));
+        assert_eq!(nybble::decimal_add_with_carry(0, 0, 0), (0, 0));
+        assert_eq!(nybble::decimal_subtract_with_carry(0, 1, 0), (9, 1));
+        assert_eq!(nybble::decimal_subtract_with_carry(0, 0, 1), (9, 1));
+        assert_eq!(nybble::decimal_subtract_with_carry(0, 9, 1), (0, 1));
+        assert_eq!(nybble::decimal_subtract_with_carry(9, 9, 0), (0, 0));
+        assert_eq!(nybble::decimal_subtract_with_carry(5, 4, 1), (0, 0));
+
+        // every pair of digits agrees with plain integer arithmetic
+        for n in 0..10 {
+            for m in 0..10 {
+                for carry in 0..2 {
+                    let (digit, out) = nybble::decimal_add_with_carry(n, m, carry);
+                    assert_eq!(out * 10 + digit, n + m + carry);
+                    let (digit, out) = nybble::decimal_subtract_with_carry(n, m, carry);
+                    assert_eq!(
+                        i16::from(digit) - i16::from(out) * 10,
+                        i16::from(n) - i16::from(m) - i16::from(carry)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "digit out of range")]
+    fn nybble_add_rejects_malformed_digit() {
+        // 0xF + 0xF + 1 still fits a u8, so this would silently give a wrong digit
+        let _ = nybble::decimal_add_with_carry(0xF, 0xF, 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "carry out of range")]
+    fn nybble_add_rejects_malformed_carry() {
+        let _ = nybble::decimal_add_with_carry(9, 9, 2);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "digit out of range")]
+    fn nybble_subtract_rejects_malformed_digit() {
+        let _ = nybble::decimal_subtract_with_carry(0xC, 3, 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "carry out of range")]
+    fn nybble_subtract_rejects_malformed_carry() {
+        let _ = nybble::decimal_subtract_with_carry(5, 3, 7);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "digit out of range")]
+    fn nybble_mul_rejects_malformed_digit() {
+        let _ = nybble::decimal_mul_with_carry(0xA, 9, 0);
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(Infinint::from(1998).sign(), Sign::Positive);
+        assert_eq!(Infinint::from(-1998).sign(), Sign::Negative);
+        assert_eq!(Infinint::from(0).sign(), Sign::Zero);
+        assert_eq!(Infinint::new().sign(), Sign::Zero);
+
+        let negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0],
+        };
+        assert_eq!(negative_zero.sign(), Sign::Zero);
+        let padded_negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0, 0],
+        };
+        assert_eq!(padded_negative_zero.sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn is_positive_is_negative() {
+        let x = Infinint::from(1998);
+        assert!(x.is_positive());
+        assert!(!x.is_negative());
+        let y = Infinint::from(-1998);
+        assert!(!y.is_positive());
+        assert!(y.is_negative());
+        assert!(!Infinint::new().is_positive());
+        assert!(!Infinint::new().is_negative());
+
+        let negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0],
+        };
+        assert!(negative_zero.negative());
+        assert!(!negative_zero.is_negative());
+        assert!(!negative_zero.is_positive());
+        let padded_negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0, 0],
+        };
+        assert!(!padded_negative_zero.is_negative());
+        assert!(!padded_negative_zero.is_positive());
+
+        for n in -50..50 {
+            let x = Infinint::from(n);
+            assert_eq!(x.is_positive(), n > 0);
+            assert_eq!(x.is_negative(), n < 0);
+        }
+    }
+
+    #[test]
+    fn primitive_comparison() {
+        let x = Infinint::from(42);
+        assert_eq!(x, 42);
+        assert_eq!(42, x);
+        assert_ne!(x, -42);
+        assert!(x > 41 && x < 43);
+        assert!(41 < x && 43 > x);
+
+        let y = Infinint::from(-1000);
+        assert!(y < 0 && y > -1001);
+        assert!(y < -999);
+        assert!(0 > y && -1001 < y);
+        assert_eq!(Infinint::new(), 0);
+        assert!(Infinint::new() > -1 && Infinint::new() < 1);
+    }
+
+    #[test]
+    fn is_divisible_by() {
+        for x in -200..200_i64 {
+            for d in [1, 2, 3, 5, 9, 10, -2, -3, 4, 7, 11, 99, 100, 101].iter() {
+                assert_eq!(
+                    Infinint::from(x).is_divisible_by(&Infinint::from(*d)),
+                    x % d == 0
+                );
+            }
+        }
+        assert!(Infinint::from(0).is_divisible_by(&Infinint::from(0)));
+        assert!(!Infinint::from(7).is_divisible_by(&Infinint::from(0)));
+
+        let big = Infinint::factorial(40);
+        assert!(big.is_divisible_by(&Infinint::factorial(39)));
+        assert!(big.is_divisible_by(&Infinint::from(9)));
+        assert!(!(&big + &Infinint::from(1)).is_divisible_by(&Infinint::from(3)));
+        let prime = Infinint::from(1_000_000_007);
+        assert!((&big * &prime).is_divisible_by(&prime));
+        assert!(!(&(&big * &prime) + &Infinint::from(1)).is_divisible_by(&prime));
+    }
+
+    #[test]
+    fn digit_sum_digital_root() {
+        assert_eq!(Infinint::from(12345).digit_sum(), Infinint::from(15));
+        assert_eq!(Infinint::from(12345).digital_root(), 6);
+        assert_eq!(Infinint::from(-1998).digit_sum(), Infinint::from(27));
+        assert_eq!(Infinint::from(-1998).digital_root(), 9);
+        assert_eq!(Infinint::from(0).digit_sum(), Infinint::from(0));
+        assert_eq!(Infinint::from(0).digital_root(), 0);
+        assert_eq!(Infinint::from(u128::MAX).digit_sum(), Infinint::from(165));
+        for x in 1..200 {
+            assert_eq!(Infinint::from(x).digital_root(), (1 + (x - 1) % 9) as u8);
+        }
+    }
+
+    #[test]
+    fn happy_numbers() {
+        assert_eq!(
+            Infinint::from(19).sum_of_digit_squares(),
+            Infinint::from(82)
+        );
+        assert_eq!(Infinint::new().sum_of_digit_squares(), Infinint::new());
+        assert_eq!(
+            Infinint::from(-1998).sum_of_digit_squares(),
+            Infinint::from(1 + 81 + 81 + 64)
+        );
+        let nines: Infinint = "9".repeat(1000).parse().unwrap();
+        assert_eq!(nines.sum_of_digit_squares(), Infinint::from(81_000));
+
+        assert!(Infinint::from(19).is_happy());
+        assert!(Infinint::from(1).is_happy());
+        assert!(Infinint::from(-7).is_happy());
+        assert!(!Infinint::from(2).is_happy());
+        assert!(!Infinint::from(4).is_happy());
+        assert!(!Infinint::new().is_happy());
+        assert!(Infinint::pow10(100).is_happy());
+        assert!(!nines.is_happy());
+
+        // the happy numbers up to 50, from OEIS A007770
+        let happy: Vec<i32> = (1..=50).filter(|n| Infinint::from(*n).is_happy()).collect();
+        assert_eq!(happy, [1, 7, 10, 13, 19, 23, 28, 31, 32, 44, 49]);
+    }
+
+    #[test]
+    fn assign_i128() {
+        let values: [i128; 9] = [5, -12, 1_000_000, -999_999, 0, 7, i128::MAX, -1, i128::MIN];
+        let mut acc = Infinint::new();
+        let mut expected = 0i128;
+        for x in values.iter() {
+            acc += *x;
+            expected = expected.wrapping_add(*x);
+        }
+        // i128::MAX + i128::MIN is -1, so the wrapping total is exact here
+        assert_eq!(acc, Infinint::from(expected));
+
+        let mut acc = Infinint::from(100);
+        acc -= 250;
+        assert_eq!(acc, Infinint::from(-150));
+        acc -= -150;
+        assert_eq!(acc, Infinint::new());
+        assert!(!acc.negative());
+        acc -= i128::MIN;
+        assert_eq!(acc, -Infinint::from(i128::MIN));
+
+        let mut acc = Infinint::from(-3);
+        acc *= 7;
+        assert_eq!(acc, Infinint::from(-21));
+        acc *= -2;
+        assert_eq!(acc, Infinint::from(42));
+        acc *= i128::MAX;
+        assert_eq!(acc, &Infinint::from(i128::MAX) * &Infinint::from(42));
+        acc *= 0;
+        assert!(acc.is_zero());
+        assert!(!acc.negative());
+    }
+
+    #[test]
+    fn mul_assign() {
+        let two = Infinint::from(2);
+        let mut acc = Infinint::from(1);
+        for _ in 0..10 {
+            acc *= &two;
         }
+        assert_eq!(acc, Infinint::from(1024));
+
+        acc *= &Infinint::from(-3);
+        assert_eq!(acc, Infinint::from(-3072));
     }
 
-    fn infinint_subtract(
-        n: &Infinint,
-        m: &Infinint,
-        negate_n: bool,
-        negate_m: bool,
-        negate_result: bool,
-    ) -> Infinint {
-        let n_negative = if negate_n == false {
-            n.negative
-        } else {
-            !n.negative
-        };
-        let m_negative = if negate_m == false {
-            m.negative
-        } else {
-            !m.negative
-        };
+    #[test]
+    fn multi_byte_addition_subtraction_comparison() {
+        let a = Infinint::from(10_000);
+        let b = Infinint::from(10_001);
+        assert_eq!(&a + &Infinint::from(1), b);
+        assert!(a < b);
+        assert_ne!(a, b);
+        assert_eq!(&b - &a, Infinint::from(1));
+        assert_eq!(
+            &Infinint::from(100) - &Infinint::from(99),
+            Infinint::from(1)
+        );
 
-        if n_negative == false && m_negative == true {
-            return Infinint::infinint_add(n, m, negate_n, !negate_m, negate_result);
-        } else if n_negative == true && m_negative == false {
-            return Infinint::infinint_add(n, m, !negate_n, negate_m, !negate_result);
-        } else if n_negative == true && m_negative == true {
-            return Infinint::infinint_subtract(m, n, !negate_m, !negate_n, negate_result);
+        let x = 1_000_000_000_000_000_i64;
+        for y in [1, 99, 10_000, 999_999_999, 12_345_678_901_234].iter() {
+            let c = Infinint::from(x);
+            let d = Infinint::from(*y);
+            assert_eq!(&c + &d, Infinint::from(x + y));
+            assert_eq!(&c - &d, Infinint::from(x - y));
+            assert_eq!(&d - &c, Infinint::from(y - x));
         }
+    }
 
-        match Infinint::infinint_cmp(n, m, negate_n, negate_m) {
-            cmp::Ordering::Less => {
-                return Infinint::infinint_subtract(m, n, negate_m, negate_n, !negate_result);
+    #[test]
+    fn negative_ordering_across_lengths() {
+        assert!(Infinint::from(-1000) < Infinint::from(-5));
+        assert!(Infinint::from(-5) > Infinint::from(-1000));
+        assert!(Infinint::from(-100) < Infinint::from(-99));
+        for x in -1100..1100 {
+            let a = Infinint::from(x);
+            for y in [-1000, -101, -100, -99, -10, -1, 0, 1, 99, 1000].iter() {
+                assert_eq!(a.cmp(&Infinint::from(*y)), x.cmp(y));
             }
-            cmp::Ordering::Equal => return Infinint::from(0),
-            cmp::Ordering::Greater => (),
         }
+    }
 
-        let result_digits_vec =
-            Infinint::op_digits(&n.digits_vec, &m.digits_vec, decimal_subtract_with_carry);
+    #[test]
+    fn division_remainder() {
+        for x in -25..25 {
+            for y in -25..25 {
+                if y == 0 {
+                    continue;
+                }
+                let a = Infinint::from(x);
+                let b = Infinint::from(y);
+                assert_eq!(&a / &b, Infinint::from(x / y));
+                assert_eq!(&a % &b, Infinint::from(x % y));
+            }
+        }
 
-        let result_negative = if negate_result == false { false } else { true };
+        let a = Infinint::from(121_932_631_112_635_269_u64);
+        let b = Infinint::from(987_654_321_u64);
+        assert_eq!(&a / &b, Infinint::from(123_456_789_u64));
+        assert_eq!(&(&a + &Infinint::from(5)) % &b, Infinint::from(5));
+        assert!(!(Infinint::from(-3) / Infinint::from(7)).negative());
 
-        Infinint {
-            negative: result_negative,
-            digits_vec: result_digits_vec,
-        }
+        let dividend: Infinint =
+            "30414093201713378043612608166064768844377641568960512000000000001"
+                .parse()
+                .unwrap();
+        let divisor: Infinint = "-1000000000000000000000000000007".parse().unwrap();
+        let quotient: Infinint = "-30414093201713378043612608165851870".parse().unwrap();
+        let remainder: Infinint = "191965647922655223742839036911".parse().unwrap();
+        assert_eq!(&dividend / &divisor, quotient);
+        assert_eq!(&dividend % &divisor, remainder);
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
     }
-}
 
-impl fmt::Debug for Infinint {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\nnegative: {}\n", self.negative)?;
-        write!(f, "{}", format!("digits: [\n"))?;
-        self.digits_vec.iter()
-            .cloned()
-            .map(|d| (d, decimal_digits(d).unwrap()))
-            .map(|(d, (lo, hi))| write!(f, "{}", format!(
-                    "\t{:04b}_{:04b} -> ({}, {})\n",
-                    (0xF0 & d) >> 4,
-                    0xF & d,
-                    lo,
-                    hi))).collect::<std::fmt::Result>()?;
-        write!(f, "]")
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn division_by_zero() {
+        let _ = &Infinint::from(1) / &Infinint::from(0);
     }
-}
 
-impl fmt::Display for Infinint {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let raw_digits = self.digits();
-        let num_digits = raw_digits.len();
-        let num_chars = num_digits
-            + if !f.alternate() {
-                (num_digits - 1) / 3
-            } else {
-                0
-            };
+    #[test]
+    fn num_digits() {
+        assert_eq!(Infinint::from(0).num_digits(), 1);
+        assert_eq!(Infinint::from(7).num_digits(), 1);
+        assert_eq!(Infinint::from(10).num_digits(), 2);
+        assert_eq!(Infinint::from(-137).num_digits(), 3);
+        assert_eq!(Infinint::from(1998).num_digits(), 4);
+        assert_eq!(Infinint::from(u128::MAX).num_digits(), 39);
+    }
 
-        let number = raw_digits.iter()
-                            .cloned()
-                            .map(u8::into)
-                            .map(|x: u32| std::char::from_digit(x, 10))
-                            .flatten()
-                            .rev();
-        if !f.alternate() {
-            let add_commas = |(i, x)| { 
-                if (num_chars - i) % 3 == 0 { 
-                    Some(',') 
-                } else { 
-                    None 
-                }.into_iter().chain(std::iter::once(x))
-            };
-            let number = number.enumerate() // Default display, we insert commas where necessary by chaining an option with the current digit.
-                     .flat_map(add_commas);
-            f.pad_integral(!self.negative, "", &number.collect::<String>())
-        } else {
-            f.pad_integral(!self.negative, "", &number.collect::<String>())
+    #[test]
+    fn bit_length() {
+        assert_eq!(Infinint::from(255).bit_length(), 8);
+        assert_eq!(Infinint::from(256).bit_length(), 9);
+        assert_eq!(Infinint::from(0).bit_length(), 0);
+        assert_eq!(Infinint::from(1).bit_length(), 1);
+        assert_eq!(Infinint::from(-1).bit_length(), 1);
+        assert_eq!(Infinint::from(u64::MAX).bit_length(), 64);
+        assert_eq!(Infinint::from(u128::MAX).bit_length(), 128);
+        assert_eq!(Infinint::from(u128::MAX >> 1).bit_length(), 127);
+        for i in 0..128 {
+            assert_eq!(Infinint::from(1_u128 << i).bit_length(), i + 1);
+            assert_eq!(Infinint::from((1_u128 << i) - 1).bit_length(), i);
         }
+        assert_eq!(Infinint::from(2).pow(1000).bit_length(), 1001);
+        assert_eq!(
+            (&Infinint::from(2).pow(1000) - &Infinint::from(1)).bit_length(),
+            1000
+        );
     }
-}
 
-impl From<u128> for Infinint {
-    fn from(n: u128) -> Infinint {
-        let digits_vec = Infinint::digits_vec_from_int(n);
+    #[test]
+    fn trailing_zeros_base10() {
+        assert_eq!(Infinint::from(120_000).trailing_zeros_base10(), 4);
+        assert_eq!(Infinint::from(5).trailing_zeros_base10(), 0);
+        assert_eq!(Infinint::from(0).trailing_zeros_base10(), 0);
+        assert_eq!(Infinint::from(10).trailing_zeros_base10(), 1);
+        assert_eq!(Infinint::from(-1_000).trailing_zeros_base10(), 3);
+        assert_eq!(Infinint::from(1_010).trailing_zeros_base10(), 1);
+        assert_eq!(Infinint::from(10).pow(45).trailing_zeros_base10(), 45);
+    }
 
-        Infinint {
-            negative: false,
-            digits_vec,
+    #[test]
+    fn isqrt() {
+        assert_eq!(Infinint::from(0).isqrt(), Infinint::from(0));
+        assert_eq!(Infinint::from(1).isqrt(), Infinint::from(1));
+        assert_eq!(Infinint::from(144).isqrt(), Infinint::from(12));
+        assert_eq!(Infinint::from(143).isqrt(), Infinint::from(11));
+        for x in 0..500_u32 {
+            let expected = (f64::from(x)).sqrt() as u32;
+            assert_eq!(Infinint::from(x).isqrt(), Infinint::from(expected));
         }
+
+        let square: Infinint = "152415787532388367504953515625361987875019051998750190521"
+            .parse()
+            .unwrap();
+        let root: Infinint = "12345678901234567890123456789".parse().unwrap();
+        assert_eq!(&root * &root, square);
+        assert_eq!(square.isqrt(), root);
+        assert_eq!(
+            (&square - &Infinint::from(1)).isqrt(),
+            &root - &Infinint::from(1)
+        );
     }
-}
 
-impl From<i128> for Infinint {
-    fn from(n: i128) -> Infinint {
-        let negative = n < 0;
-        let digits_vec = Infinint::digits_vec_from_int(n.abs() as u128);
+    #[test]
+    #[should_panic]
+    fn isqrt_negative() {
+        Infinint::from(-4).isqrt();
+    }
 
-        Infinint {
-            negative,
-            digits_vec,
-        }
+    #[test]
+    fn gcd() {
+        let gcd = |x: i64, y: i64| Infinint::from(x).gcd(&Infinint::from(y));
+        assert_eq!(gcd(48, 36), Infinint::from(12));
+        assert_eq!(gcd(17, 5), Infinint::from(1));
+        assert_eq!(gcd(0, 7), Infinint::from(7));
+        assert_eq!(gcd(7, 0), Infinint::from(7));
+        assert_eq!(gcd(0, -7), Infinint::from(7));
+        assert_eq!(gcd(0, 0), Infinint::from(0));
+        assert_eq!(gcd(-48, -36), Infinint::from(12));
+        assert_eq!(gcd(1_234_567_890, 9_876_543_210), Infinint::from(90));
     }
-}
 
-impl From<usize> for Infinint {
-    fn from(n: usize) -> Infinint {
-        // since usize < u128, conversion is safe
-        Infinint::from(n as u128)
+    #[test]
+    fn clone() {
+        let a = Infinint::from(-1998);
+        let b = a.clone();
+        assert_eq!(a, b);
+        assert_eq!(b.negative, a.negative);
+        assert_eq!(b.digits_vec, a.digits_vec);
     }
-}
 
-impl From<isize> for Infinint {
-    fn from(n: isize) -> Infinint {
-        // since isize < i128, conversion is safe
-        Infinint::from(n as i128)
+    #[test]
+    fn factorial() {
+        assert_eq!(Infinint::factorial(0), Infinint::from(1));
+        assert_eq!(Infinint::factorial(1), Infinint::from(1));
+        assert_eq!(Infinint::factorial(10), Infinint::from(3_628_800));
+
+        let expected: Infinint =
+            "30414093201713378043612608166064768844377641568960512000000000000"
+                .parse()
+                .unwrap();
+        assert_eq!(Infinint::factorial(50), expected);
     }
-}
 
-impl From<u64> for Infinint {
-    fn from(n: u64) -> Infinint {
-        Infinint::from(u128::from(n))
+    #[test]
+    fn digits_trimming() {
+        assert_eq!(Infinint::from(0).digits(), [0]);
+        assert_eq!(Infinint::from(10).digits(), [0, 1]);
+        assert_eq!(Infinint::from(100).digits(), [0, 0, 1]);
+        assert_eq!(Infinint::from(1000).digits(), [0, 0, 0, 1]);
+
+        let padded = Infinint {
+            negative: false,
+            digits_vec: vec![0b0000_0000, 0b0001_0000, 0, 0],
+        };
+        assert_eq!(padded.digits(), [0, 0, 1]);
+        let padded_zero = Infinint {
+            negative: false,
+            digits_vec: vec![0, 0],
+        };
+        assert_eq!(padded_zero.digits(), [0]);
     }
-}
 
-impl From<i64> for Infinint {
-    fn from(n: i64) -> Infinint {
-        Infinint::from(i128::from(n))
+    #[test]
+    fn try_into_unsigned() {
+        assert_eq!(Infinint::from(5).try_into_unsigned(), Ok(Infinint::from(5)));
+        assert_eq!(Infinint::new().try_into_unsigned(), Ok(Infinint::new()));
+        let negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0],
+        };
+        assert!(negative_zero.try_into_unsigned().is_ok());
+
+        let x = -Infinint::factorial(30);
+        let err = x.clone().try_into_unsigned().unwrap_err();
+        assert_eq!(err, x);
+        assert!(err.negative());
     }
-}
 
-impl From<u32> for Infinint {
-    fn from(n: u32) -> Infinint {
-        Infinint::from(u128::from(n))
+    #[test]
+    fn as_unsigned() {
+        assert!(Infinint::new_unsigned().as_unsigned().is_some());
+        assert!(!Infinint::new_unsigned().negative());
+        assert_eq!(Infinint::new_unsigned(), Infinint::new());
+
+        let x = Infinint::factorial(30);
+        assert_eq!(x.as_unsigned(), Some(&x));
+        let negative_zero = Infinint {
+            negative: true,
+            digits_vec: vec![0],
+        };
+        assert!(negative_zero.as_unsigned().is_some());
+
+        assert_eq!((-&x).as_unsigned(), None);
+        assert_eq!(Infinint::from(-1).as_unsigned(), None);
+        assert_eq!(Infinint::from(i128::MIN).as_unsigned(), None);
+        // a value which goes negative along the way is rejected at the end
+        let mut total = Infinint::new_unsigned();
+        total += &Infinint::from(3);
+        total = &total - &Infinint::from(4);
+        assert_eq!(total.as_unsigned(), None);
     }
-}
 
-impl From<i32> for Infinint {
-    fn from(n: i32) -> Infinint {
-        Infinint::from(i128::from(n))
+    #[test]
+    fn overflowing_sub() {
+        let sub = |n: i128, m: i128| Infinint::from(n).overflowing_sub(&Infinint::from(m));
+        assert_eq!(sub(5, 3), (Infinint::from(2), false));
+        assert_eq!(sub(3, 5), (Infinint::from(2), true));
+        assert_eq!(sub(5, 5), (Infinint::new(), false));
+        assert_eq!(sub(0, 1), (Infinint::from(1), true));
+        assert_eq!(sub(1000, 1), (Infinint::from(999), false));
+        assert_eq!(sub(-3, 5), (Infinint::from(8), true));
+        assert_eq!(sub(-3, -5), (Infinint::from(2), false));
+        assert!(!sub(3, 5).0.negative());
     }
-}
 
-impl From<u16> for Infinint {
-    fn from(n: u16) -> Infinint {
-        Infinint::from(u128::from(n))
+    #[test]
+    fn cmp_abs() {
+        let cmp_abs = |x: i64, y: i64| Infinint::from(x).cmp_abs(&Infinint::from(y));
+        assert_eq!(cmp_abs(-5, 3), cmp::Ordering::Greater);
+        assert_eq!(cmp_abs(-3, 3), cmp::Ordering::Equal);
+        assert_eq!(cmp_abs(3, -5), cmp::Ordering::Less);
+        assert_eq!(cmp_abs(0, 0), cmp::Ordering::Equal);
+        for x in -120..120_i64 {
+            for y in [-1000, -101, -7, 0, 7, 99, 100].iter() {
+                assert_eq!(cmp_abs(x, *y), x.abs().cmp(&y.abs()));
+            }
+        }
+
+        let mut values: Vec<Infinint> = [12, -1998, 0, -3, 7]
+            .iter()
+            .map(|x| Infinint::from(*x))
+            .collect();
+        values.sort_by(|a, b| a.cmp_abs(b));
+        let expected: Vec<Infinint> = [0, -3, 7, 12, -1998]
+            .iter()
+            .map(|x| Infinint::from(*x))
+            .collect();
+        assert_eq!(values, expected);
     }
-}
 
-impl From<i16> for Infinint {
-    fn from(n: i16) -> Infinint {
-        Infinint::from(i128::from(n))
+    #[test]
+    fn succ_pred_range() {
+        for x in -20..20 {
+            assert_eq!(Infinint::from(x).succ(), Infinint::from(x + 1));
+            assert_eq!(Infinint::from(x).pred(), Infinint::from(x - 1));
+        }
+        assert_eq!(Infinint::from(999).succ(), Infinint::from(1000));
+        assert_eq!(Infinint::from(-1000).succ(), Infinint::from(-999));
+        assert!(!Infinint::from(-1).succ().negative());
+
+        let range: Vec<Infinint> = Infinint::range(Infinint::from(8), Infinint::from(12)).collect();
+        let expected: Vec<Infinint> = (8..12).map(Infinint::from).collect();
+        assert_eq!(range, expected);
+
+        let range: Vec<Infinint> = Infinint::range(Infinint::from(-2), Infinint::from(2)).collect();
+        let expected: Vec<Infinint> = (-2..2).map(Infinint::from).collect();
+        assert_eq!(range, expected);
+
+        assert_eq!(
+            Infinint::range(Infinint::from(5), Infinint::from(5)).count(),
+            0
+        );
+        assert_eq!(
+            Infinint::range(Infinint::from(6), Infinint::from(5)).count(),
+            0
+        );
     }
-}
 
-impl From<u8> for Infinint {
-    fn from(n: u8) -> Infinint {
-        Infinint::from(u128::from(n))
+    #[test]
+    fn increment() {
+        let mut x = Infinint::from(99);
+        x.increment();
+        assert_eq!(x, Infinint::from(100));
+        assert_eq!(x.digits_vec, [0b0000_0000, 0b0001_0000]);
+
+        let mut x = Infinint::from(-1);
+        x.increment();
+        assert_eq!(x, Infinint::from(0));
+        assert!(!x.negative());
+
+        let mut x = Infinint::from(-100);
+        x.increment();
+        assert_eq!(x, Infinint::from(-99));
+        assert_eq!(x.digits_vec, [0b1001_1001]);
+
+        let mut x = -&Infinint::new();
+        x.increment();
+        assert_eq!(x, Infinint::from(1));
+
+        let mut x = Infinint::from(-1000);
+        for expected in -1000..1000 {
+            assert_eq!(x, Infinint::from(expected));
+            assert_eq!(x.digits_vec, Infinint::from(expected).digits_vec);
+            x.increment();
+        }
     }
-}
 
-impl From<i8> for Infinint {
-    fn from(n: i8) -> Infinint {
-        Infinint::from(i128::from(n))
+    #[test]
+    fn decrement() {
+        let mut x = Infinint::from(100);
+        x.decrement();
+        assert_eq!(x, Infinint::from(99));
+        assert_eq!(x.digits_vec, [0b1001_1001]);
+
+        let mut x = Infinint::from(0);
+        x.decrement();
+        assert_eq!(x, Infinint::from(-1));
+
+        let mut x = Infinint::from(-1);
+        x.decrement();
+        assert_eq!(x, Infinint::from(-2));
+
+        let mut x = Infinint::from(-99);
+        x.decrement();
+        assert_eq!(x, Infinint::from(-100));
+
+        let mut x = Infinint::from(1000);
+        for expected in (-1000..=1000).rev() {
+            assert_eq!(x, Infinint::from(expected));
+            assert_eq!(x.digits_vec, Infinint::from(expected).digits_vec);
+            x.decrement();
+        }
     }
-}
 
-impl cmp::Ord for Infinint {
-    fn cmp(&self, other: &Infinint) -> cmp::Ordering {
-        Infinint::infinint_cmp(self, other, false, false)
+    #[test]
+    fn abs_diff() {
+        let abs_diff = |x: i64, y: i64| Infinint::from(x).abs_diff(&Infinint::from(y));
+        assert_eq!(abs_diff(3, 10), Infinint::from(7));
+        assert_eq!(abs_diff(10, 3), Infinint::from(7));
+        assert_eq!(abs_diff(-3, 10), Infinint::from(13));
+        assert_eq!(abs_diff(-10, -3), Infinint::from(7));
+        for x in [0, 5, -5, 1998].iter() {
+            assert!(abs_diff(*x, *x).is_zero());
+            assert!(!abs_diff(*x, *x).negative());
+        }
+        for x in -20..20 {
+            for y in -20..20 {
+                assert_eq!(abs_diff(x, y), Infinint::from((x - y).abs()));
+                assert!(!abs_diff(x, y).negative());
+            }
+        }
     }
-}
 
-impl cmp::PartialOrd for Infinint {
-    fn partial_cmp(&self, other: &Infinint) -> Option<cmp::Ordering> {
-        Some(self.cmp(other))
+    #[test]
+    fn clamp_min_max() {
+        let clamp = |x: i32| Infinint::from(x).clamp(Infinint::from(-10), Infinint::from(10));
+        assert_eq!(clamp(-100), Infinint::from(-10));
+        assert_eq!(clamp(-10), Infinint::from(-10));
+        assert_eq!(clamp(3), Infinint::from(3));
+        assert_eq!(clamp(10), Infinint::from(10));
+        assert_eq!(clamp(100), Infinint::from(10));
+
+        for x in -150..150 {
+            for y in -150..150 {
+                let min = Infinint::min(Infinint::from(x), Infinint::from(y));
+                let max = Infinint::max(Infinint::from(x), Infinint::from(y));
+                assert_eq!(min, Infinint::from(cmp::min(x, y)));
+                assert_eq!(max, Infinint::from(cmp::max(x, y)));
+            }
+        }
     }
-}
 
-impl cmp::Eq for Infinint {}
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "min > max")]
+    fn clamp_invalid_range() {
+        Infinint::from(0).clamp(Infinint::from(1), Infinint::from(-1));
+    }
 
-impl cmp::PartialEq for Infinint {
-    fn eq(&self, other: &Infinint) -> bool {
-        self.cmp(other) == cmp::Ordering::Equal
+    #[test]
+    fn from_digits() {
+        let x = Infinint::from_digits(false, &[8, 9, 9, 1]).unwrap();
+        assert_eq!(x, Infinint::from(1998));
+        assert_eq!(x.digits_vec, [0b1000_1001, 0b1001_0001]);
+
+        let y = Infinint::from_digits(true, &[7, 3, 1, 0, 0, 0]).unwrap();
+        assert_eq!(y, Infinint::from(-137));
+        assert_eq!(y.digits_vec, [0b0111_0011, 0b0001_0000]);
+
+        let zero = Infinint::from_digits(true, &[0, 0, 0]).unwrap();
+        assert_eq!(zero.digits_vec, [0]);
+        assert!(!zero.negative());
+
+        assert_eq!(
+            Infinint::from_digits(false, &[1, 10]),
+            Err(NybbleError::InvalidDigit {
+                position: 1,
+                found: 10
+            })
+        );
+        assert_eq!(Infinint::from_digits(false, &[]), Err(NybbleError::Empty));
     }
-}
 
-impl ops::Neg for &Infinint {
-    type Output = Infinint;
+    #[test]
+    fn digit_orders() {
+        assert_eq!(Infinint::from(1998).digits_be(), [1, 9, 9, 8]);
+        assert_eq!(Infinint::from(-137).digits_be(), [1, 3, 7]);
+        assert_eq!(Infinint::from(0).digits_be(), [0]);
 
-    fn neg(self) -> Infinint {
-        let new_negative = !self.negative;
-        Infinint {
-            negative: new_negative,
-            digits_vec: self.digits_vec.to_vec(),
+        for x in [0, 7, 10, 137, 1998, 100_000, -123_456_789].iter() {
+            let a = Infinint::from(*x);
+            assert_eq!(a.digits(), Infinint::from(*x).into_digits());
         }
     }
-}
 
-impl ops::Add<&Infinint> for &Infinint {
-    type Output = Infinint;
-    fn add(self, other: &Infinint) -> Infinint {
-        Infinint::infinint_add(self, other, false, false, false)
+    #[test]
+    fn pow() {
+        for x in -10..10_i64 {
+            for e in 0..8 {
+                assert_eq!(Infinint::from(x).pow(e), Infinint::from(x.pow(e)));
+            }
+        }
+        let expected: Infinint = "1267650600228229401496703205376".parse().unwrap();
+        assert_eq!(Infinint::from(2).pow(100), expected);
     }
-}
 
-impl ops::Sub<&Infinint> for &Infinint {
-    type Output = Infinint;
+    #[test]
+    fn rem_euclid() {
+        let rem_euclid = |x: i64, m: i64| Infinint::from(x).rem_euclid(&Infinint::from(m));
+        assert_eq!(rem_euclid(-7, 3), Infinint::from(2));
+        assert_eq!(rem_euclid(7, 3), Infinint::from(1));
+        for x in -30..30_i64 {
+            for m in [-7, -3, -1, 1, 2, 3, 10].iter() {
+                let remainder = rem_euclid(x, *m);
+                assert_eq!(remainder, Infinint::from(x.rem_euclid(*m)));
+                assert!(!remainder.negative());
+            }
+        }
 
-    fn sub(self, other: &Infinint) -> Infinint {
-        Infinint::infinint_subtract(self, other, false, false, false)
+        let x: Infinint = "-30414093201713378043612608166064768844377641568960512000000000001"
+            .parse()
+            .unwrap();
+        let m = Infinint::from(1_000_000_007);
+        assert_eq!(x.rem_euclid(&m), &m - &(&x.abs() % &m));
     }
-}
 
-fn decimal_digits(n: u8) -> Result<(u8, u8), &'static str> {
-    let high = decimal_digit_high(n)?;
-    let low = decimal_digit_low(n)?;
-    Ok((high, low))
-}
+    #[test]
+    fn div_euclid() {
+        for (x, d) in [(-7, 3), (7, -3), (-7, -3), (7, 3), (-6, 3), (0, -3)].iter() {
+            let x = Infinint::from(*x);
+            let d = Infinint::from(*d);
+            let q = x.div_euclid(&d);
+            let r = x.rem_euclid(&d);
+            assert_eq!(&(&q * &d) + &r, x);
+            assert!(!r.negative() && r < d.abs());
+        }
+        for x in -30..30_i64 {
+            for d in [-7, -3, -1, 1, 2, 3, 10].iter() {
+                let q = Infinint::from(x).div_euclid(&Infinint::from(*d));
+                assert_eq!(q, Infinint::from(x.div_euclid(*d)));
+            }
+        }
+    }
 
-fn decimal_digit_high(n: u8) -> Result<u8, &'static str> {
-    decimal_digit_nybble((0xF0 & n) >> 4)
-}
+    #[test]
+    #[should_panic]
+    fn rem_euclid_zero_modulus() {
+        Infinint::from(7).rem_euclid(&Infinint::from(0));
+    }
 
-fn decimal_digit_low(n: u8) -> Result<u8, &'static str> {
-    decimal_digit_nybble(0x0F & n)
-}
+    #[test]
+    fn mul_digit() {
+        assert_eq!(Infinint::from(12345).mul_digit(3), Infinint::from(37035));
+        assert_eq!(Infinint::from(12345).mul_digit(0), Infinint::new());
+        assert_eq!(Infinint::from(12345).mul_digit(0).to_bytes(), [0]);
+        assert!(!Infinint::from(-12345).mul_digit(0).negative());
+        assert_eq!(Infinint::from(-12345).mul_digit(1), Infinint::from(-12345));
+        assert_eq!(Infinint::from(99).mul_digit(9), Infinint::from(891));
+        assert_eq!(Infinint::from(9).mul_digit(9), Infinint::from(81));
 
-fn decimal_digit_nybble(n: u8) -> Result<u8, &'static str> {
-    if n < 10 {
-        Ok(n)
-    } else {
-        Err("digit too large")
+        let x = Infinint::factorial(50);
+        for d in 0..10 {
+            assert_eq!(x.mul_digit(d), &x * &Infinint::from(d));
+        }
     }
-}
 
-fn decimal_add_with_carry(n: u8, m: u8, carry: u8) -> (u8, u8) {
-    let result = n + m + carry;
-    let carry = result / 10;
-    let result = result % 10;
-    (result, carry)
-}
+    #[test]
+    #[should_panic(expected = "digit out of range: 10")]
+    fn mul_digit_out_of_range() {
+        Infinint::from(1998).mul_digit(10);
+    }
 
-fn decimal_subtract_with_carry(n: u8, m: u8, carry: u8) -> (u8, u8) {
-    let (result, carry) = if n >= (m + carry) {
-        (n - m - carry, 0)
-    } else {
-        ((n + 10) - m - carry, 1)
-    };
-    (result, carry)
-}
+    #[test]
+    fn pow10() {
+        assert_eq!(Infinint::pow10(0), Infinint::one());
+        assert_eq!(Infinint::pow10(1), Infinint::from(10));
+        assert_eq!(Infinint::pow10(3), Infinint::from(1000));
+        // 100000 has six digits, so the one sits in the lower nybble of the third byte
+        assert_eq!(Infinint::pow10(5).as_bytes(), &[0x00, 0x00, 0x01]);
+        assert_eq!(Infinint::pow10(4).as_bytes(), &[0x00, 0x00, 0x10]);
+        for n in 0..40 {
+            let x = Infinint::pow10(n);
+            assert_eq!(x, Infinint::from(10).pow(n as u32));
+            assert_eq!(x.num_digits(), n + 1);
+            assert!(!x.negative());
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn pow_big() {
+        for base in [-7, -2, 0, 1, 3, 10].iter() {
+            let x = Infinint::from(*base);
+            for exp in 0..40 {
+                assert_eq!(
+                    x.pow_big(&Infinint::from(exp)),
+                    x.pow(exp),
+                    "{}^{}",
+                    base,
+                    exp
+                );
+            }
+        }
+
+        let three_200: Infinint =
+            "265613988875874769338781322035779626829233452653394495974574961739092490901302182994384699044001"
+                .parse()
+                .unwrap();
+        assert_eq!(Infinint::from(3).pow_big(&Infinint::from(200)), three_200);
+        let seven_123: Infinint =
+            "88523570369346801684435811372718127585670061114702144933569245260093253728999880981421881473709365496343"
+                .parse()
+                .unwrap();
+        assert_eq!(Infinint::from(7).pow_big(&Infinint::from(123)), seven_123);
+
+        // exponents past u32 are fine when the result stays small
+        let huge: Infinint = "1000000000000000000000000000001".parse().unwrap();
+        assert_eq!(Infinint::from(-1).pow_big(&huge), Infinint::from(-1));
+        assert_eq!(Infinint::from(1).pow_big(&huge), Infinint::from(1));
+        assert_eq!(Infinint::new().pow_big(&huge), Infinint::new());
+        assert_eq!(Infinint::new().pow_big(&Infinint::new()), Infinint::from(1));
+    }
 
     #[test]
-    fn infinint_declaration() {
-        let test = Infinint::from(1998);
-        assert_eq!(test.negative, false);
-        assert_eq!(test.digits_vec, [0b1000_1001, 0b1001_0001]);
+    #[should_panic(expected = "cannot raise to a negative power")]
+    fn pow_big_negative_exponent() {
+        Infinint::from(2).pow_big(&Infinint::from(-1));
     }
 
     #[test]
-    fn simple_addition_subtraction() {
-        for x in 0..100 {
-            for y in 0..100 {
-                let a = Infinint::from(x);
-                let b = Infinint::from(y);
-                assert_eq!(&a + &b, Infinint::from(x + y));
-                assert_eq!(&a - &b, Infinint::from(x - y));
+    fn pow_mod() {
+        let pow_mod = |x: i64, e: i64, m: i64| {
+            Infinint::from(x).pow_mod(&Infinint::from(e), &Infinint::from(m))
+        };
+        assert_eq!(pow_mod(2, 10, 1000), Infinint::from(24));
+        assert_eq!(pow_mod(7, 0, 13), Infinint::from(1));
+        assert_eq!(pow_mod(7, 0, 1), Infinint::from(0));
+        assert_eq!(pow_mod(0, 0, 5), Infinint::from(1));
+        assert_eq!(pow_mod(0, 3, 5), Infinint::from(0));
+        assert_eq!(pow_mod(-7, 13, 1000), Infinint::from(593));
+        assert_eq!(pow_mod(3, 5, -7), Infinint::from(5));
+
+        // checked against Python's three-argument pow
+        let x: Infinint = "123456789012345678901234567890".parse().unwrap();
+        let e: Infinint = "98765432109876543210".parse().unwrap();
+        let m: Infinint = "1000000000000000000000000000057".parse().unwrap();
+        let expected: Infinint = "327455648218123532448608791417".parse().unwrap();
+        assert_eq!(x.pow_mod(&e, &m), expected);
+    }
+
+    #[test]
+    fn pow_mod_power_of_ten() {
+        // the general path, reducing with a full division every time
+        fn pow_mod_by_division(base: &Infinint, exp: u32, modulus: &Infinint) -> Infinint {
+            let mut result = Infinint::from(1);
+            for _ in 0..exp {
+                result = (&result * base).rem_euclid(modulus);
             }
+            result.rem_euclid(modulus)
+        }
+
+        let seven = Infinint::from(7);
+        let m = Infinint::from(100_000);
+        assert_eq!(seven.pow_mod(&Infinint::from(1000), &m), Infinint::from(1));
+        assert_eq!(
+            seven.pow_mod(&Infinint::from(1000), &m),
+            pow_mod_by_division(&seven, 1000, &m)
+        );
+
+        let cases: [(i128, u32, i128); 6] = [
+            (-3, 77, 100_000_000),
+            (123_456_789, 41, 1_000_000_000_000),
+            (2, 100, 10),
+            (99, 5, -1000),
+            (12, 3, 1),
+            (10, 9, 1_000),
+        ];
+        for (base, exp, modulus) in cases.iter() {
+            let base = Infinint::from(*base);
+            let modulus = Infinint::from(*modulus);
+            assert_eq!(
+                base.pow_mod(&Infinint::from(*exp), &modulus),
+                pow_mod_by_division(&base, *exp, &modulus),
+                "{}^{} mod {}",
+                base,
+                exp,
+                modulus
+            );
         }
+        assert_eq!(
+            Infinint::from(-3).pow_mod(&Infinint::from(77), &Infinint::from(100_000_000)),
+            Infinint::from(30_248_237)
+        );
+
+        let huge_exp = &Infinint::from(10).pow(20) + &Infinint::from(3);
+        let m = Infinint::from(1_000_000_000_000u64);
+        assert_eq!(
+            Infinint::from(123_456_789).pow_mod(&huge_exp, &m),
+            Infinint::from(154_860_897_069u64)
+        );
     }
 
     #[test]
-    fn complex_addition_subtraction() {
-        for x in -25..25 {
-            for y in -25..25 {
-                let a = Infinint::from(x);
-                let b = Infinint::from(y);
-                assert_eq!(&a + &b, Infinint::from(x + y));
-                assert_eq!(&a - &b, Infinint::from(x - y));
+    #[should_panic]
+    fn pow_mod_zero_modulus() {
+        Infinint::from(2).pow_mod(&Infinint::from(3), &Infinint::from(0));
+    }
+
+    #[test]
+    fn checked_mul_pow() {
+        let x = Infinint::from(123_456_789);
+        assert_eq!(x.checked_mul(&x), Some(&x * &x));
+        assert_eq!(x.checked_pow(3), Some(x.pow(3)));
+        assert_eq!(
+            Infinint::from(0).checked_pow(u32::MAX),
+            Some(Infinint::from(0))
+        );
+        assert_eq!(
+            Infinint::from(-1).checked_pow(u32::MAX),
+            Some(Infinint::from(-1))
+        );
+        assert_eq!(Infinint::from(2).checked_pow(u32::MAX), None);
+        assert_eq!(Infinint::from(10).checked_pow(1_000_000), None);
+
+        // rejected from the digit counts alone, without multiplying
+        let mut digits = vec![0; Infinint::MAX_CHECKED_DIGITS / 2 + 1];
+        digits.push(1);
+        let huge = Infinint::from_digits(false, &digits).unwrap();
+        assert_eq!(huge.checked_mul(&huge), None);
+        assert_eq!(huge.checked_pow(2), None);
+
+        // the approximation never rejects a result that fits
+        for x in [2, 3, 7, 99, 999_999_999_999_999_999_i64].iter() {
+            for e in [1, 2, 3, 17, 64, 100].iter() {
+                let x = Infinint::from(*x);
+                let digits = x.pow(*e).num_digits();
+                assert!(!x.pow_lower_bound_exceeds(*e, digits));
             }
         }
+
+        // the boundaries, at a limit that is cheap to reach
+        let ten = Infinint::from(10);
+        let p49 = ten.pow(49);
+        assert_eq!(p49.checked_mul_with_limit(&ten, 51), Some(ten.pow(50)));
+        assert_eq!(p49.checked_mul_with_limit(&ten, 50), None);
+        assert_eq!(
+            p49.checked_mul_with_limit(&Infinint::new(), 10),
+            Some(Infinint::new())
+        );
+        assert_eq!(
+            Infinint::new().checked_mul_with_limit(&p49, 1),
+            Some(Infinint::new())
+        );
+        assert_eq!(ten.checked_pow_with_limit(99, 100), Some(ten.pow(99)));
+        assert_eq!(ten.checked_pow_with_limit(100, 100), None);
+
+        let nines = &ten.pow(10) - &Infinint::from(1);
+        let square = &nines * &nines;
+        assert_eq!(square.num_digits(), 20);
+        assert_eq!(nines.checked_mul_with_limit(&nines, 20), Some(square));
+        assert_eq!(nines.checked_mul_with_limit(&nines, 19), None);
+        assert_eq!(
+            Infinint::from(-3).checked_pow_with_limit(20, 10),
+            Some(Infinint::from(3).pow(20))
+        );
+        assert_eq!(Infinint::from(-3).checked_pow_with_limit(21, 10), None);
+        assert_eq!(
+            Infinint::from(7).checked_pow_with_limit(0, 1),
+            Some(Infinint::from(1))
+        );
     }
 }